@@ -0,0 +1,74 @@
+// crates/admin/src/amount.rs
+
+use anyhow::{bail, Context, Result};
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+const SOL_DECIMALS: usize = 9;
+
+// Parses a SOL amount like "1", "0.5" or "0.000000001" into lamports
+// without going through floating point.
+pub fn parse_sol(value: &str) -> Result<u64> {
+    let value = value.trim();
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        bail!("empty SOL amount");
+    }
+    if fraction.len() > SOL_DECIMALS {
+        bail!("{value}: SOL has at most {SOL_DECIMALS} decimal places");
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        bail!("{value}: not a SOL amount");
+    }
+
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse()? };
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<SOL_DECIMALS$}").parse()?
+    };
+
+    whole
+        .checked_mul(LAMPORTS_PER_SOL)
+        .and_then(|lamports| lamports.checked_add(fraction))
+        .with_context(|| format!("{value}: amount too large"))
+}
+
+pub fn format_sol(lamports: u64) -> String {
+    let whole = lamports / LAMPORTS_PER_SOL;
+    let fraction = lamports % LAMPORTS_PER_SOL;
+    if fraction == 0 {
+        return format!("{whole} SOL");
+    }
+    let fraction = format!("{fraction:0>SOL_DECIMALS$}");
+    format!("{whole}.{} SOL", fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_whole_and_fractional_sol() {
+        assert_eq!(parse_sol("1").unwrap(), 1_000_000_000);
+        assert_eq!(parse_sol("0.5").unwrap(), 500_000_000);
+        assert_eq!(parse_sol(".25").unwrap(), 250_000_000);
+        assert_eq!(parse_sol("0.000000001").unwrap(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_amounts() {
+        assert!(parse_sol("").is_err());
+        assert!(parse_sol("-1").is_err());
+        assert!(parse_sol("1.0000000001").is_err());
+        assert!(parse_sol("abc").is_err());
+        assert!(parse_sol("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn formats_lamports() {
+        assert_eq!(format_sol(1_500_000_000), "1.5 SOL");
+        assert_eq!(format_sol(2_000_000_000), "2 SOL");
+        assert_eq!(format_sol(1), "0.000000001 SOL");
+    }
+}
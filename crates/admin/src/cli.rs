@@ -0,0 +1,919 @@
+// crates/admin/src/cli.rs
+
+use std::path::PathBuf;
+
+use afrodevsols_client::state::{FaucetConfig, GrantRecord};
+use afrodevsols_client::{allowlist, args, constants, decode, instructions, pda, Instruction, Pubkey};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_signer::Signer;
+
+use crate::amount::parse_sol;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Parser)]
+#[command(name = "afrodevsols-admin", about = "Operate the afrodevsols faucet")]
+pub struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "AFRODEVSOLS_RPC_URL", default_value = "https://api.devnet.solana.com")]
+    pub url: String,
+
+    /// Signer keypair file. Defaults to the Solana CLI wallet.
+    #[arg(long, short = 'k', env = "AFRODEVSOLS_KEYPAIR")]
+    pub keypair: Option<PathBuf>,
+
+    /// Simulate instead of sending, and print the decoded account changes
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create FaucetConfig and the treasury vault
+    Initialize(InitializeArgs),
+    /// Send SOL from the signer into the treasury
+    Fund {
+        /// Amount in SOL
+        amount: String,
+    },
+    /// Change config fields. Only the flags given are updated.
+    UpdateConfig(UpdateConfigArgs),
+    /// Set referral unlock thresholds and multi-level shares. Only the flags given are updated.
+    ReferralConfig(ReferralConfigArgs),
+    /// Set per-referrer caps and the bonus expiry. Only the flags given are updated.
+    ReferralLimits {
+        /// New referrals per referrer per day (0 = unlimited)
+        #[arg(long)]
+        daily_cap: Option<u64>,
+        /// Confirmed referrals per referrer (0 = unlimited)
+        #[arg(long)]
+        lifetime_cap: Option<u64>,
+        /// Days before an uncollected bonus can be forfeited (0 = never)
+        #[arg(long)]
+        bonus_expiry_days: Option<u64>,
+    },
+    /// Set the daily streak bonus. Only the flags given are updated.
+    StreakConfig {
+        /// Bonus per consecutive day after the first, in basis points
+        #[arg(long)]
+        bonus_bps: Option<u16>,
+        /// Cap on the total streak bonus, in basis points
+        #[arg(long)]
+        max_bps: Option<u16>,
+    },
+    /// Set the low-treasury alert. Only the flags given are updated.
+    Alerts {
+        /// Alert when an outflow drops the treasury below this many SOL
+        #[arg(long)]
+        low_water: Option<String>,
+        /// Lift the auto-pause once funded back above this many SOL
+        #[arg(long)]
+        high_water: Option<String>,
+        #[arg(long)]
+        auto_pause: Option<bool>,
+    },
+    /// Set how payouts scale with treasury runway. Only the flags given are updated.
+    Scaling(ScalingArgs),
+    /// Set up relayer-sponsored claims. Only the flags given are updated.
+    Sponsor {
+        #[arg(long)]
+        enabled: Option<bool>,
+        #[arg(long)]
+        relayer: Option<Pubkey>,
+        /// SOL refunded to the relayer per sponsored claim
+        #[arg(long)]
+        reimbursement: Option<String>,
+        /// SOL refunded per day (0 = unlimited)
+        #[arg(long)]
+        daily_limit: Option<String>,
+    },
+    /// Set the claim outflow circuit breaker. Only the flags given are updated.
+    CircuitBreaker {
+        /// SOL claimable per window (0 = off)
+        #[arg(long)]
+        limit: Option<String>,
+        /// Window length in minutes
+        #[arg(long)]
+        window_minutes: Option<u8>,
+    },
+    /// Switch off one or more features
+    Pause {
+        #[arg(required = true, value_delimiter = ',')]
+        features: Vec<Feature>,
+        /// Reason code recorded with the pause (1 = manual)
+        #[arg(long, default_value_t = constants::PAUSE_REASON_MANUAL)]
+        reason: u8,
+    },
+    /// Switch paused features back on
+    Unpause {
+        #[arg(required = true, value_delimiter = ',')]
+        features: Vec<Feature>,
+    },
+    /// Clear the outflow window and resume claims the circuit breaker paused
+    ResetCircuitBreaker,
+    /// Set what a wallet needs before it can claim a tier. Only the flags given are updated.
+    TierRequirements {
+        /// Tier index, 0-3
+        tier: u8,
+        /// Claims the wallet must already have made
+        #[arg(long)]
+        min_claims: Option<u64>,
+        /// Days since the wallet's first claim
+        #[arg(long)]
+        min_age_days: Option<i64>,
+        /// Confirmed referrals the wallet must have made
+        #[arg(long)]
+        min_referrals: Option<u64>,
+    },
+    /// Let a program claim through CPI
+    AllowIntegrator { program: Pubkey },
+    /// Stop a program claiming through CPI
+    RemoveIntegrator { program: Pubkey },
+    /// Create a leaderboard. Once it exists, every matching instruction must pass it.
+    InitLeaderboard { kind: Board },
+    /// Ban a wallet from claiming
+    Block { wallet: Pubkey },
+    /// Lift a ban
+    Unblock { wallet: Pubkey },
+    /// Send SOL straight from the treasury, bypassing claim rules
+    Grant {
+        recipient: Pubkey,
+        /// Amount in SOL
+        amount: String,
+        #[arg(long)]
+        reason: String,
+        /// Record the grant as public
+        #[arg(long)]
+        public: bool,
+    },
+    /// Escrow a grant for the recipient to accept before it expires
+    EscrowGrant {
+        recipient: Pubkey,
+        /// Amount in SOL
+        amount: String,
+        #[arg(long)]
+        reason: String,
+        #[arg(long)]
+        public: bool,
+        /// Days the recipient has to accept
+        #[arg(long, default_value_t = 30)]
+        days: i64,
+    },
+    /// Escrow a grant released one milestone at a time
+    MilestoneGrant {
+        recipient: Pubkey,
+        #[arg(long)]
+        reason: String,
+        #[arg(long)]
+        public: bool,
+        /// "AMOUNT:DESCRIPTION" with the amount in SOL. Repeat once per milestone, in order.
+        #[arg(long = "milestone", required = true)]
+        milestones: Vec<String>,
+    },
+    /// Release one milestone of a milestone grant
+    ApproveMilestone {
+        /// GrantRecord address
+        grant: Pubkey,
+        /// Milestone index, from 0
+        index: u8,
+    },
+    /// Return a milestone grant's unreleased SOL to the treasury
+    CancelMilestoneGrant { grant: Pubkey },
+    /// Close a settled grant and reclaim its rent
+    CloseGrant { grant: Pubkey },
+    /// Create a voucher paid from the treasury. Only the code's public key goes on chain.
+    CreateVoucher {
+        /// Secret code. Use something long and random.
+        code: String,
+        /// Amount in SOL per redemption
+        amount: String,
+        /// Days until the voucher expires
+        #[arg(long, default_value_t = 30)]
+        days: i64,
+        /// Number of wallets that can redeem it
+        #[arg(long, default_value_t = 1)]
+        max_uses: u32,
+    },
+    /// Close an expired or used-up voucher and reclaim its rent
+    CloseVoucher { code: String },
+    /// Open a campaign with its own budget escrowed from the treasury
+    CreateCampaign(CreateCampaignArgs),
+    /// End a campaign early
+    CloseCampaign { id: u64 },
+    /// Return a finished campaign's unspent budget to the treasury
+    SweepCampaign { id: u64 },
+    /// Close a swept campaign and reclaim its rent
+    CloseCampaignAccount { id: u64 },
+    /// Escrow a bounty reward from the treasury
+    CreateBounty {
+        /// Reward in SOL
+        reward: String,
+        /// The task. Only its hash goes on chain.
+        #[arg(long)]
+        description: String,
+        /// Days until submissions close
+        #[arg(long, default_value_t = 14)]
+        days: i64,
+    },
+    /// Pay a bounty's reward to a submitter
+    AwardBounty { id: u64, winner: Pubkey },
+    /// Return an unawarded bounty's reward once review has closed
+    ExpireBounty { id: u64 },
+    /// Close an awarded or expired bounty and reclaim its rent
+    CloseBounty { id: u64 },
+    /// Withdraw SOL from the treasury to the signer
+    Withdraw {
+        /// Amount in SOL
+        amount: String,
+    },
+    /// Close a wallet's ClaimerRecord and reclaim its rent
+    CloseRecord {
+        wallet: Pubkey,
+        /// Where the rent goes. Defaults to the signer.
+        #[arg(long)]
+        rent_receiver: Option<Pubkey>,
+    },
+    /// Rewrite a config left by an older program build in the current layout
+    MigrateConfig,
+    /// Grow a claimer, referral or grant record left by an older program build
+    MigrateRecord { address: Pubkey },
+    /// Print the current FaucetConfig and treasury balance
+    Show,
+}
+
+#[derive(Args)]
+pub struct InitializeArgs {
+    #[arg(long, default_value = "0.1")]
+    min: String,
+    #[arg(long, default_value = "1")]
+    max: String,
+    /// Four comma-separated SOL amounts
+    #[arg(long, value_delimiter = ',', default_value = "0.1,0.25,0.5,1")]
+    tier_amounts: Vec<String>,
+    /// Four comma-separated cooldowns in seconds
+    #[arg(long, value_delimiter = ',', default_value = "21600,43200,86400,172800")]
+    tier_seconds: Vec<i64>,
+    #[arg(long, default_value = "50")]
+    daily_limit: String,
+    #[arg(long, default_value = "0.05")]
+    referral_bonus_claimer: String,
+    #[arg(long, default_value = "0.1")]
+    referral_bonus_referrer: String,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Feature {
+    All,
+    Claims,
+    Tier0,
+    Tier1,
+    Tier2,
+    Tier3,
+    ReferralConfirmation,
+    ReferralPayout,
+    Grants,
+    Withdrawals,
+}
+
+impl Feature {
+    fn flag(self) -> u16 {
+        match self {
+            Feature::All => constants::PAUSE_ALL,
+            Feature::Claims => constants::PAUSE_CLAIMS,
+            Feature::Tier0 => constants::PAUSE_TIER_0,
+            Feature::Tier1 => constants::PAUSE_TIER_0 << 1,
+            Feature::Tier2 => constants::PAUSE_TIER_0 << 2,
+            Feature::Tier3 => constants::PAUSE_TIER_0 << 3,
+            Feature::ReferralConfirmation => constants::PAUSE_REFERRAL_CONFIRMATION,
+            Feature::ReferralPayout => constants::PAUSE_REFERRAL_PAYOUT,
+            Feature::Grants => constants::PAUSE_GRANTS,
+            Feature::Withdrawals => constants::PAUSE_WITHDRAWALS,
+        }
+    }
+}
+
+fn feature_mask(features: &[Feature]) -> u16 {
+    features.iter().fold(0, |mask, feature| mask | feature.flag())
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Board {
+    Claimers,
+    Referrers,
+    Donors,
+}
+
+impl Board {
+    fn kind(self) -> u8 {
+        match self {
+            Board::Claimers => constants::LEADERBOARD_KIND_CLAIMERS,
+            Board::Referrers => constants::LEADERBOARD_KIND_REFERRERS,
+            Board::Donors => constants::LEADERBOARD_KIND_DONORS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ScalingMode {
+    Off,
+    Linear,
+    Steps,
+}
+
+impl ScalingMode {
+    fn value(self) -> u8 {
+        match self {
+            ScalingMode::Off => constants::SCALING_MODE_OFF,
+            ScalingMode::Linear => constants::SCALING_MODE_LINEAR,
+            ScalingMode::Steps => constants::SCALING_MODE_STEPS,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct UpdateConfigArgs {
+    #[arg(long)]
+    min: Option<String>,
+    #[arg(long)]
+    max: Option<String>,
+    /// Four comma-separated SOL amounts
+    #[arg(long, value_delimiter = ',')]
+    tier_amounts: Option<Vec<String>>,
+    /// Four comma-separated cooldowns in seconds
+    #[arg(long, value_delimiter = ',')]
+    tier_seconds: Option<Vec<i64>>,
+    #[arg(long)]
+    daily_limit: Option<String>,
+    #[arg(long)]
+    referral_enabled: Option<bool>,
+    #[arg(long)]
+    referral_bonus_claimer: Option<String>,
+    #[arg(long)]
+    referral_bonus_referrer: Option<String>,
+    #[arg(long)]
+    new_authority: Option<Pubkey>,
+}
+
+#[derive(Args)]
+pub struct ReferralConfigArgs {
+    /// Claims the referred wallet must make before the referrer is credited
+    #[arg(long)]
+    min_claims: Option<u64>,
+    /// Days since the referred wallet's first claim before the referrer is credited
+    #[arg(long)]
+    min_days: Option<u64>,
+    /// Claims a wallet needs before it can refer
+    #[arg(long)]
+    referrer_min_claims: Option<u64>,
+    /// Ancestors above the referrer that earn a share
+    #[arg(long)]
+    ancestor_levels: Option<u8>,
+    /// First ancestor's share of the referrer bonus, in basis points
+    #[arg(long)]
+    ancestor_bps: Option<u16>,
+    /// Applied again for each level further up, in basis points
+    #[arg(long)]
+    decay_bps: Option<u16>,
+}
+
+#[derive(Args)]
+pub struct ScalingArgs {
+    #[arg(long)]
+    mode: Option<ScalingMode>,
+    /// Linear: full payout at or above this many SOL
+    #[arg(long)]
+    full_balance: Option<String>,
+    /// Linear: floor payout at or below this many SOL
+    #[arg(long)]
+    floor_balance: Option<String>,
+    /// Linear: payout multiplier at the floor, in basis points
+    #[arg(long)]
+    floor_bps: Option<u16>,
+    /// Steps: four comma-separated SOL balances
+    #[arg(long, value_delimiter = ',')]
+    step_thresholds: Option<Vec<String>>,
+    /// Steps: four comma-separated multipliers in basis points
+    #[arg(long, value_delimiter = ',')]
+    step_bps: Option<Vec<u16>>,
+}
+
+#[derive(Args)]
+pub struct CreateCampaignArgs {
+    /// Campaign id, unique per faucet
+    id: u64,
+    #[arg(long)]
+    name: String,
+    /// Budget in SOL
+    #[arg(long)]
+    budget: String,
+    /// Unix time the campaign opens. Defaults to now.
+    #[arg(long)]
+    starts_at: Option<i64>,
+    /// Days the campaign runs
+    #[arg(long, default_value_t = 7)]
+    days: i64,
+    /// Up to four comma-separated claimable SOL amounts
+    #[arg(long, value_delimiter = ',', required = true)]
+    tier_amounts: Vec<String>,
+    /// Seconds between claims by one wallet
+    #[arg(long, default_value_t = 86_400)]
+    cooldown_seconds: i64,
+    /// SOL one wallet can claim in total (0 = no limit)
+    #[arg(long, default_value = "0")]
+    per_wallet_limit: String,
+    /// File with one allowed wallet per line. Open to everyone if omitted.
+    #[arg(long)]
+    allowlist: Option<PathBuf>,
+}
+
+// What building a command's instructions may read from the cluster.
+// Only the commands that need it ask.
+pub trait Chain {
+    fn account(&self, address: &Pubkey) -> Result<Option<Vec<u8>>>;
+    fn now(&self) -> Result<i64>;
+
+    fn config(&self) -> Result<FaucetConfig> {
+        let data = self
+            .account(&pda::faucet_config().0)?
+            .ok_or_else(|| anyhow!("faucet is not initialized"))?;
+        Ok(decode::faucet_config(&data)?)
+    }
+
+    fn grant_record(&self, address: &Pubkey) -> Result<GrantRecord> {
+        let data = self
+            .account(address)?
+            .ok_or_else(|| anyhow!("no grant record at {address}"))?;
+        Ok(decode::grant_record(&data)?)
+    }
+}
+
+// The instructions `command` sends when signed by `authority`.
+pub fn instructions(command: Command, authority: Pubkey, chain: &impl Chain) -> Result<Vec<Instruction>> {
+    let ix = match command {
+        Command::Initialize(a) => instructions::initialize(
+            authority,
+            args::Initialize {
+                min_amount: parse_sol(&a.min)?,
+                max_amount: parse_sol(&a.max)?,
+                cooldown_tier_amounts: tier_amounts(&a.tier_amounts)?,
+                cooldown_tier_seconds: tier_seconds(&a.tier_seconds)?,
+                daily_global_limit: parse_sol(&a.daily_limit)?,
+                referral_bonus_claimer: parse_sol(&a.referral_bonus_claimer)?,
+                referral_bonus_referrer: parse_sol(&a.referral_bonus_referrer)?,
+            },
+        ),
+        Command::Fund { amount } => {
+            let has_board = chain.config()?.has_leaderboard(constants::LEADERBOARD_KIND_DONORS);
+            instructions::fund_treasury(authority, parse_sol(&amount)?, has_board)
+        }
+        Command::UpdateConfig(a) => instructions::update_config(
+            authority,
+            args::UpdateConfig {
+                min_amount: parse_sol_opt(a.min)?,
+                max_amount: parse_sol_opt(a.max)?,
+                cooldown_tier_amounts: a.tier_amounts.as_deref().map(tier_amounts).transpose()?,
+                cooldown_tier_seconds: a.tier_seconds.as_deref().map(tier_seconds).transpose()?,
+                daily_global_limit: parse_sol_opt(a.daily_limit)?,
+                referral_enabled: a.referral_enabled,
+                referral_bonus_claimer: parse_sol_opt(a.referral_bonus_claimer)?,
+                referral_bonus_referrer: parse_sol_opt(a.referral_bonus_referrer)?,
+                new_authority: a.new_authority,
+            },
+        ),
+        Command::ReferralConfig(a) => instructions::update_referral_config(
+            authority,
+            args::UpdateReferralConfig {
+                referral_min_claims: a.min_claims,
+                referral_min_days: a.min_days,
+                referrer_min_claims: a.referrer_min_claims,
+                referral_ancestor_levels: a.ancestor_levels,
+                referral_ancestor_bps: a.ancestor_bps,
+                referral_decay_bps: a.decay_bps,
+            },
+        ),
+        Command::ReferralLimits { daily_cap, lifetime_cap, bonus_expiry_days } => {
+            instructions::update_referral_limits(
+                authority,
+                args::UpdateReferralLimits {
+                    referral_daily_cap: daily_cap,
+                    referral_lifetime_cap: lifetime_cap,
+                    referral_bonus_expiry_days: bonus_expiry_days,
+                },
+            )
+        }
+        Command::StreakConfig { bonus_bps, max_bps } => instructions::update_streak_config(
+            authority,
+            args::UpdateStreakConfig {
+                streak_bonus_bps: bonus_bps,
+                streak_bonus_max_bps: max_bps,
+            },
+        ),
+        Command::Alerts { low_water, high_water, auto_pause } => instructions::update_treasury_alerts(
+            authority,
+            args::UpdateTreasuryAlerts {
+                low_water_mark: parse_sol_opt(low_water)?,
+                high_water_mark: parse_sol_opt(high_water)?,
+                auto_pause_on_low: auto_pause,
+            },
+        ),
+        Command::Scaling(a) => instructions::update_runway_scaling(
+            authority,
+            args::UpdateRunwayScaling {
+                scaling_mode: a.mode.map(ScalingMode::value),
+                scaling_full_balance: parse_sol_opt(a.full_balance)?,
+                scaling_floor_balance: parse_sol_opt(a.floor_balance)?,
+                scaling_floor_bps: a.floor_bps,
+                scaling_step_thresholds: a.step_thresholds.as_deref().map(tier_amounts).transpose()?,
+                scaling_step_bps: a
+                    .step_bps
+                    .map(|bps| bps.try_into().map_err(|_| anyhow!("expected exactly 4 step multipliers")))
+                    .transpose()?,
+            },
+        ),
+        Command::Sponsor { enabled, relayer, reimbursement, daily_limit } => {
+            instructions::update_sponsor_config(
+                authority,
+                args::UpdateSponsorConfig {
+                    enabled,
+                    relayer,
+                    reimbursement: parse_sol_opt(reimbursement)?,
+                    daily_limit: parse_sol_opt(daily_limit)?,
+                },
+            )
+        }
+        Command::CircuitBreaker { limit, window_minutes } => instructions::update_circuit_breaker(
+            authority,
+            args::UpdateCircuitBreaker {
+                limit: parse_sol_opt(limit)?,
+                window_minutes,
+            },
+        ),
+        Command::Pause { features, reason } => {
+            instructions::set_paused(authority, feature_mask(&features), true, reason)
+        }
+        Command::Unpause { features } => {
+            instructions::set_paused(authority, feature_mask(&features), false, 0)
+        }
+        Command::ResetCircuitBreaker => instructions::reset_circuit_breaker(authority),
+        Command::TierRequirements { tier, min_claims, min_age_days, min_referrals } => {
+            instructions::update_tier_requirements(
+                authority,
+                args::UpdateTierRequirements {
+                    tier_index: tier,
+                    min_claims,
+                    min_account_age: min_age_days.map(days_to_seconds).transpose()?,
+                    min_referrals,
+                },
+            )
+        }
+        Command::AllowIntegrator { program } => instructions::set_integrator(authority, program, true),
+        Command::RemoveIntegrator { program } => instructions::set_integrator(authority, program, false),
+        Command::InitLeaderboard { kind } => instructions::initialize_leaderboard(authority, kind.kind()),
+        Command::Block { wallet } => instructions::block_wallet(authority, wallet, true),
+        Command::Unblock { wallet } => instructions::block_wallet(authority, wallet, false),
+        Command::Grant { recipient, amount, reason, public } => {
+            // The GrantRecord address is seeded with the config's grant nonce
+            let nonce = chain.config()?.grant_nonce;
+            instructions::special_grant(authority, recipient, nonce, parse_sol(&amount)?, reason, public)
+        }
+        Command::EscrowGrant { recipient, amount, reason, public, days } => {
+            let nonce = chain.config()?.grant_nonce;
+            instructions::escrow_grant(
+                authority,
+                recipient,
+                nonce,
+                parse_sol(&amount)?,
+                reason,
+                public,
+                days_to_seconds(days)?,
+            )
+        }
+        Command::MilestoneGrant { recipient, reason, public, milestones } => {
+            let (amounts, hashes) = milestones
+                .iter()
+                .map(|milestone| parse_milestone(milestone))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            let nonce = chain.config()?.grant_nonce;
+            instructions::create_milestone_grant(authority, recipient, nonce, reason, public, amounts, hashes)
+        }
+        Command::ApproveMilestone { grant, index } => {
+            let recipient = chain.grant_record(&grant)?.recipient;
+            instructions::approve_milestone(authority, grant, recipient, index)
+        }
+        Command::CancelMilestoneGrant { grant } => instructions::cancel_milestone_grant(authority, grant),
+        Command::CloseGrant { grant } => {
+            let is_milestone = chain.grant_record(&grant)?.grant_type == constants::GRANT_TYPE_MILESTONE;
+            instructions::close_grant_record(authority, grant, is_milestone)
+        }
+        Command::CreateVoucher { code, amount, days, max_uses } => instructions::create_voucher(
+            authority,
+            &code,
+            parse_sol(&amount)?,
+            days_from_now(chain, days)?,
+            max_uses,
+        ),
+        Command::CloseVoucher { code } => {
+            instructions::close_voucher(authority, &instructions::voucher_keypair(&code).pubkey())
+        }
+        Command::CreateCampaign(a) => {
+            let starts_at = match a.starts_at {
+                Some(starts_at) => starts_at,
+                None => chain.now()?,
+            };
+            let ends_at = starts_at
+                .checked_add(days_to_seconds(a.days)?)
+                .context("campaign ends too far in the future")?;
+            let allowlist_root = match &a.allowlist {
+                Some(path) => allowlist::root(&read_wallets(path)?),
+                None => [0u8; 32],
+            };
+            instructions::create_campaign(
+                authority,
+                args::CreateCampaign {
+                    campaign_id: a.id,
+                    name: a.name,
+                    budget: parse_sol(&a.budget)?,
+                    starts_at,
+                    ends_at,
+                    tier_amounts: campaign_tiers(&a.tier_amounts)?,
+                    cooldown_seconds: a.cooldown_seconds,
+                    per_wallet_limit: parse_sol(&a.per_wallet_limit)?,
+                    allowlist_root,
+                },
+            )
+        }
+        Command::CloseCampaign { id } => instructions::close_campaign(authority, id),
+        Command::SweepCampaign { id } => instructions::sweep_campaign(id, authority),
+        Command::CloseCampaignAccount { id } => instructions::close_campaign_account(authority, id),
+        Command::CreateBounty { reward, description, days } => {
+            // The Bounty address is seeded with the config's bounty nonce
+            let bounty_id = chain.config()?.bounty_nonce;
+            instructions::create_bounty(
+                authority,
+                bounty_id,
+                args::CreateBounty {
+                    reward: parse_sol(&reward)?,
+                    deadline: days_from_now(chain, days)?,
+                    description_hash: hash_text(&description),
+                },
+            )
+        }
+        Command::AwardBounty { id, winner } => instructions::award_bounty(authority, id, winner),
+        Command::ExpireBounty { id } => instructions::expire_bounty(id, authority),
+        Command::CloseBounty { id } => instructions::close_bounty(authority, id),
+        Command::Withdraw { amount } => instructions::withdraw_treasury(authority, parse_sol(&amount)?),
+        Command::CloseRecord { wallet, rent_receiver } => instructions::close_claimer_record(
+            authority,
+            wallet,
+            rent_receiver.unwrap_or(authority),
+        ),
+        Command::MigrateConfig => instructions::migrate_config(authority),
+        Command::MigrateRecord { address } => instructions::migrate_record(authority, address),
+        Command::Show => bail!("show sends no transaction"),
+    };
+    Ok(vec![ix])
+}
+
+// Every account the instructions may write, once each, in order.
+pub fn writable_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut writable: Vec<Pubkey> = Vec::new();
+    for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+        if meta.is_writable && !writable.contains(&meta.pubkey) {
+            writable.push(meta.pubkey);
+        }
+    }
+    writable
+}
+
+fn parse_sol_opt(value: Option<String>) -> Result<Option<u64>> {
+    value.as_deref().map(parse_sol).transpose()
+}
+
+fn days_to_seconds(days: i64) -> Result<i64> {
+    days.checked_mul(SECONDS_PER_DAY)
+        .with_context(|| format!("{days} days is too long"))
+}
+
+fn days_from_now(chain: &impl Chain, days: i64) -> Result<i64> {
+    chain
+        .now()?
+        .checked_add(days_to_seconds(days)?)
+        .with_context(|| format!("{days} days from now is too far in the future"))
+}
+
+fn tier_amounts(values: &[String]) -> Result<[u64; 4]> {
+    let lamports = values.iter().map(|v| parse_sol(v)).collect::<Result<Vec<_>>>()?;
+    lamports
+        .try_into()
+        .map_err(|_| anyhow!("expected exactly 4 tier amounts"))
+}
+
+fn tier_seconds(values: &[i64]) -> Result<[i64; 4]> {
+    values
+        .try_into()
+        .map_err(|_| anyhow!("expected exactly 4 tier cooldowns"))
+}
+
+// Campaigns may use fewer than four tiers; unused slots are 0.
+fn campaign_tiers(values: &[String]) -> Result<[u64; 4]> {
+    if values.len() > 4 {
+        bail!("a campaign has at most 4 tier amounts");
+    }
+    let mut tiers = [0u64; 4];
+    for (tier, value) in tiers.iter_mut().zip(values) {
+        *tier = parse_sol(value)?;
+    }
+    Ok(tiers)
+}
+
+// "0.5:Ship the MVP" -> (500_000_000, sha256("Ship the MVP"))
+fn parse_milestone(value: &str) -> Result<(u64, [u8; 32])> {
+    let (amount, description) = value
+        .split_once(':')
+        .with_context(|| format!("{value}: expected AMOUNT:DESCRIPTION"))?;
+    Ok((parse_sol(amount)?, hash_text(description.trim())))
+}
+
+// Descriptions live off chain; the program only keeps their hash.
+fn hash_text(text: &str) -> [u8; 32] {
+    solana_sha256_hasher::hash(text.as_bytes()).to_bytes()
+}
+
+fn read_wallets(path: &PathBuf) -> Result<Vec<Pubkey>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("reading allowlist {}", path.display()))?;
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().map_err(|e| anyhow!("{line}: {e}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anchor_lang::{AccountSerialize, AnchorDeserialize, Discriminator};
+
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const AUTHORITY: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    #[derive(Default)]
+    struct FakeChain {
+        accounts: HashMap<Pubkey, Vec<u8>>,
+    }
+
+    impl FakeChain {
+        fn with_config(edit: impl FnOnce(&mut FaucetConfig)) -> Self {
+            let mut data = FaucetConfig::DISCRIMINATOR.to_vec();
+            data.resize(FaucetConfig::LEN, 0);
+            let mut config = decode::faucet_config(&data).unwrap();
+            edit(&mut config);
+
+            let mut chain = Self::default();
+            chain.insert(pda::faucet_config().0, &config);
+            chain
+        }
+
+        fn insert(&mut self, address: Pubkey, account: &impl AccountSerialize) {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            self.accounts.insert(address, data);
+        }
+    }
+
+    impl Chain for FakeChain {
+        fn account(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+            Ok(self.accounts.get(address).cloned())
+        }
+
+        fn now(&self) -> Result<i64> {
+            Ok(NOW)
+        }
+    }
+
+    fn build(chain: &FakeChain, args: &[&str]) -> Result<Vec<Instruction>> {
+        let cli = Cli::try_parse_from(std::iter::once("afrodevsols-admin").chain(args.iter().copied()))?;
+        instructions(cli.command, AUTHORITY, chain)
+    }
+
+    fn decode_args<T: AnchorDeserialize + Discriminator>(ix: &Instruction) -> T {
+        assert_eq!(&ix.data[..8], T::DISCRIMINATOR);
+        T::deserialize(&mut &ix.data[8..]).unwrap()
+    }
+
+    #[test]
+    fn converts_days_and_rejects_overflow() {
+        let chain = FakeChain::default();
+        let ixs = build(&chain, &["tier-requirements", "1", "--min-age-days", "7"]).unwrap();
+        let sent: args::UpdateTierRequirements = decode_args(&ixs[0]);
+        assert_eq!(sent.min_account_age, Some(7 * SECONDS_PER_DAY));
+
+        let huge = i64::MAX.to_string();
+        assert!(build(&chain, &["tier-requirements", "1", "--min-age-days", &huge]).is_err());
+        assert!(build(&chain, &["create-voucher", "code", "1", "--days", &huge]).is_err());
+
+        let ixs = build(&chain, &["create-voucher", "code", "0.5", "--days", "2"]).unwrap();
+        let sent: args::CreateVoucher = decode_args(&ixs[0]);
+        assert_eq!(sent.expires_at, NOW + 2 * SECONDS_PER_DAY);
+        assert_eq!(sent.code_key, instructions::voucher_keypair("code").pubkey());
+    }
+
+    #[test]
+    fn rejects_malformed_arguments() {
+        let chain = FakeChain::default();
+        assert!(build(&chain, &["scaling", "--mode", "exponential"]).is_err());
+        assert!(build(&chain, &["scaling", "--step-bps", "1,2,3"]).is_err());
+        assert!(build(&chain, &["init-leaderboard", "funders"]).is_err());
+        assert!(build(&chain, &["milestone-grant", &Pubkey::new_unique().to_string(), "--reason", "x"]).is_err());
+        assert!(build(&chain, &["create-campaign", "1", "--name", "x", "--budget", "1", "--tier-amounts", "1,1,1,1,1"]).is_err());
+        assert!(build(&chain, &["show"]).is_err(), "Show has nothing to send");
+    }
+
+    #[test]
+    fn seeds_new_accounts_from_the_config_nonces() {
+        let chain = FakeChain::with_config(|config| {
+            config.grant_nonce = 4;
+            config.bounty_nonce = 9;
+            config.leaderboards_initialized = 1 << constants::LEADERBOARD_KIND_DONORS;
+        });
+        let recipient = Pubkey::new_unique();
+
+        let ixs = build(&chain, &["escrow-grant", &recipient.to_string(), "1", "--reason", "travel"]).unwrap();
+        let grant_record = pda::grant_record(&AUTHORITY, &recipient, 4).0;
+        assert_eq!(decode_args::<args::EscrowGrant>(&ixs[0]).expires_in, 30 * SECONDS_PER_DAY);
+        assert!(writable_accounts(&ixs).contains(&grant_record));
+
+        let ixs = build(&chain, &["create-bounty", "2", "--description", "Write docs"]).unwrap();
+        assert!(ixs[0].accounts.iter().any(|meta| meta.pubkey == pda::bounty(9).0));
+        let sent: args::CreateBounty = decode_args(&ixs[0]);
+        assert_eq!(sent.description_hash, hash_text("Write docs"));
+
+        let ixs = build(&chain, &["fund", "3"]).unwrap();
+        let board = pda::leaderboard(constants::LEADERBOARD_KIND_DONORS).0;
+        assert!(writable_accounts(&ixs).contains(&board), "Initialized board is passed");
+    }
+
+    #[test]
+    fn builds_milestone_grants_and_closes_them_with_their_schedule() {
+        let mut chain = FakeChain::with_config(|_| {});
+        let recipient = Pubkey::new_unique();
+
+        let ixs = build(
+            &chain,
+            &[
+                "milestone-grant",
+                &recipient.to_string(),
+                "--reason",
+                "MVP",
+                "--milestone",
+                "0.5:Design",
+                "--milestone",
+                "1.5: Ship",
+            ],
+        )
+        .unwrap();
+        let sent: args::CreateMilestoneGrant = decode_args(&ixs[0]);
+        assert_eq!(sent.milestone_amounts, vec![500_000_000, 1_500_000_000]);
+        assert_eq!(sent.description_hashes[1], hash_text("Ship"));
+
+        let grant = Pubkey::new_unique();
+        let mut data = GrantRecord::DISCRIMINATOR.to_vec();
+        data.resize(GrantRecord::LEN, 0);
+        let mut record = decode::grant_record(&data).unwrap();
+        record.recipient = recipient;
+        record.grant_type = constants::GRANT_TYPE_MILESTONE;
+        chain.insert(grant, &record);
+
+        let ixs = build(&chain, &["close-grant", &grant.to_string()]).unwrap();
+        let schedule = pda::grant_milestones(&grant).0;
+        assert!(writable_accounts(&ixs).contains(&schedule));
+
+        let ixs = build(&chain, &["approve-milestone", &grant.to_string(), "1"]).unwrap();
+        assert!(ixs[0].accounts.iter().any(|meta| meta.pubkey == recipient));
+        assert!(build(&chain, &["close-grant", &Pubkey::new_unique().to_string()]).is_err());
+    }
+
+    #[test]
+    fn dry_run_simulates_every_writable_account_once() {
+        let chain = FakeChain::with_config(|_| {});
+        let wallet = Pubkey::new_unique();
+
+        let ixs = build(&chain, &["block", &wallet.to_string()]).unwrap();
+        assert_eq!(writable_accounts(&ixs), vec![pda::claimer_record(&wallet).0]);
+
+        let ixs = build(&chain, &["scaling", "--mode", "steps", "--step-thresholds", "1,2,3,4"]).unwrap();
+        let sent: args::UpdateRunwayScaling = decode_args(&ixs[0]);
+        assert_eq!(sent.scaling_mode, Some(constants::SCALING_MODE_STEPS));
+        let twice = [ixs[0].clone(), ixs[0].clone()];
+        assert_eq!(writable_accounts(&twice), vec![pda::faucet_config().0]);
+    }
+}
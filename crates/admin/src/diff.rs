@@ -0,0 +1,91 @@
+// crates/admin/src/diff.rs
+
+use afrodevsols_client::state::*;
+use afrodevsols_client::decode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+
+use crate::amount::format_sol;
+use crate::rpc::AccountState;
+
+// Pretty-prints any program account, picking the type by discriminator.
+pub fn describe(data: &[u8]) -> Option<String> {
+    let discriminator = data.get(..8)?;
+    let text = match discriminator {
+        d if d == FaucetConfig::DISCRIMINATOR => format!("{:#?}", decode::faucet_config(data).ok()?),
+        d if d == ClaimerRecord::DISCRIMINATOR => format!("{:#?}", decode::claimer_record(data).ok()?),
+        d if d == GrantRecord::DISCRIMINATOR => format!("{:#?}", decode::grant_record(data).ok()?),
+        d if d == GrantMilestones::DISCRIMINATOR => format!("{:#?}", decode::grant_milestones(data).ok()?),
+        d if d == ReferralRecord::DISCRIMINATOR => format!("{:#?}", decode::referral_record(data).ok()?),
+        d if d == ReferralCode::DISCRIMINATOR => format!("{:#?}", decode::referral_code(data).ok()?),
+        d if d == DailyStats::DISCRIMINATOR => format!("{:#?}", decode::daily_stats(data).ok()?),
+        d if d == FunderRecord::DISCRIMINATOR => format!("{:#?}", decode::funder_record(data).ok()?),
+        d if d == Campaign::DISCRIMINATOR => format!("{:#?}", decode::campaign(data).ok()?),
+        d if d == CampaignClaimRecord::DISCRIMINATOR => format!("{:#?}", decode::campaign_claim_record(data).ok()?),
+        d if d == Voucher::DISCRIMINATOR => format!("{:#?}", decode::voucher(data).ok()?),
+        d if d == VoucherRedemption::DISCRIMINATOR => format!("{:#?}", decode::voucher_redemption(data).ok()?),
+        d if d == Bounty::DISCRIMINATOR => format!("{:#?}", decode::bounty(data).ok()?),
+        d if d == BountySubmission::DISCRIMINATOR => format!("{:#?}", decode::bounty_submission(data).ok()?),
+        d if d == Leaderboard::DISCRIMINATOR => format!("{:#?}", decode::leaderboard(data).ok()?),
+        _ => return None,
+    };
+    Some(text)
+}
+
+// Prints the decoded change to one account. Fixed-size accounts
+// line up field by field, so only changed lines are shown.
+pub fn print_diff(address: &Pubkey, before: Option<&AccountState>, after: Option<&AccountState>) {
+    let (before, after) = match (before, after) {
+        (None, None) => return,
+        (None, Some(after)) => {
+            println!("{address}: created with {}", format_sol(after.lamports));
+            println!("{}", render(&after.data));
+            return;
+        }
+        (Some(_), None) => {
+            println!("{address}: closed");
+            return;
+        }
+        (Some(before), Some(after)) if before == after => {
+            println!("{address}: unchanged");
+            return;
+        }
+        (Some(before), Some(after)) => (before, after),
+    };
+
+    println!("{address}: changed");
+    if before.lamports != after.lamports {
+        println!(
+            "  balance: {} -> {}",
+            format_sol(before.lamports),
+            format_sol(after.lamports)
+        );
+    }
+    if before.data == after.data {
+        return;
+    }
+
+    let old = render(&before.data);
+    let new = render(&after.data);
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    if old_lines.len() == new_lines.len() {
+        for (old, new) in old_lines.iter().zip(&new_lines).filter(|(old, new)| old != new) {
+            println!("  - {}", old.trim());
+            println!("  + {}", new.trim());
+        }
+    } else {
+        println!("--- before\n{old}\n+++ after\n{new}");
+    }
+}
+
+fn render(data: &[u8]) -> String {
+    if data.is_empty() {
+        return String::from("<no data>");
+    }
+    describe(data).unwrap_or_else(|| raw(data))
+}
+
+fn raw(data: &[u8]) -> String {
+    format!("<{} bytes, not a program account>", data.len())
+}
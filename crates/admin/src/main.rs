@@ -0,0 +1,119 @@
+// crates/admin/src/main.rs
+
+mod amount;
+mod cli;
+mod diff;
+mod rpc;
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use afrodevsols_client::{errors, pda, Instruction, Pubkey};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use amount::format_sol;
+use cli::{Chain, Cli, Command};
+use rpc::RpcClient;
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new(&cli.url);
+
+    if let Command::Show = cli.command {
+        return show(&rpc);
+    }
+
+    let signer = load_keypair(cli.keypair)?;
+    let authority = signer.pubkey();
+
+    let ixs = cli::instructions(cli.command, authority, &RpcChain(&rpc))?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&authority),
+        &[&signer],
+        rpc.latest_blockhash()?,
+    );
+
+    if cli.dry_run {
+        dry_run(&rpc, &tx, &ixs)
+    } else {
+        let signature = rpc.send_and_confirm(&tx).map_err(explain)?;
+        println!("Confirmed: {signature}");
+        Ok(())
+    }
+}
+
+fn dry_run(rpc: &RpcClient, tx: &Transaction, ixs: &[Instruction]) -> Result<()> {
+    let writable = cli::writable_accounts(ixs);
+
+    let before = rpc.accounts(&writable)?;
+    let simulation = rpc.simulate(tx, &writable)?;
+
+    for line in &simulation.logs {
+        println!("  {line}");
+    }
+    if let Some(error) = simulation.error {
+        let decoded = custom_error_code(&error).and_then(errors::decode_error);
+        match decoded {
+            Some(e) => bail!("simulation failed: {} ({e})", e.name()),
+            None => bail!("simulation failed: {error}"),
+        }
+    }
+
+    println!("\nSimulation succeeded. Account changes:");
+    for ((address, before), after) in writable.iter().zip(&before).zip(&simulation.accounts) {
+        diff::print_diff(address, before.as_ref(), after.as_ref());
+    }
+    Ok(())
+}
+
+fn show(rpc: &RpcClient) -> Result<()> {
+    let config = RpcChain(rpc).config()?;
+    let treasury = rpc.accounts(&[pda::treasury_vault().0])?.remove(0);
+
+    println!("{config:#?}");
+    println!(
+        "treasury balance: {}",
+        format_sol(treasury.map(|account| account.lamports).unwrap_or_default())
+    );
+    Ok(())
+}
+
+// Reads through RPC; `now` is this machine's clock.
+struct RpcChain<'a>(&'a RpcClient);
+
+impl Chain for RpcChain<'_> {
+    fn account(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        Ok(self.0.accounts(&[*address])?.remove(0).map(|account| account.data))
+    }
+
+    fn now(&self) -> Result<i64> {
+        Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64)
+    }
+}
+
+fn load_keypair(path: Option<PathBuf>) -> Result<Keypair> {
+    let path = match path {
+        Some(path) => path,
+        None => PathBuf::from(std::env::var("HOME").context("HOME is not set")?)
+            .join(".config/solana/id.json"),
+    };
+    read_keypair_file(&path).map_err(|e| anyhow!("reading keypair {}: {e}", path.display()))
+}
+
+// {"InstructionError":[0,{"Custom":6000}]} -> 6000
+fn custom_error_code(error: &serde_json::Value) -> Option<u32> {
+    error["InstructionError"][1]["Custom"].as_u64().map(|code| code as u32)
+}
+
+fn explain(error: anyhow::Error) -> anyhow::Error {
+    match errors::decode_error_message(&error.to_string()) {
+        Some(e) => error.context(format!("{}: {e}", e.name())),
+        None => error,
+    }
+}
@@ -0,0 +1,162 @@
+// crates/admin/src/rpc.rs
+
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, PartialEq)]
+pub struct AccountState {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+// Outcome of simulateTransaction.
+pub struct Simulation {
+    pub error: Option<Value>,
+    pub logs: Vec<String>,
+    // Post-simulation state of the requested addresses, in order
+    pub accounts: Vec<Option<AccountState>>,
+}
+
+// Minimal JSON-RPC client covering the calls the CLI makes.
+pub struct RpcClient {
+    url: String,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = ureq::post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method} returned no result"))
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("getLatestBlockhash returned no blockhash"))?
+            .parse()
+            .map_err(|e| anyhow!("bad blockhash: {e:?}"))
+    }
+
+    pub fn accounts(&self, addresses: &[Pubkey]) -> Result<Vec<Option<AccountState>>> {
+        let keys: Vec<String> = addresses.iter().map(Pubkey::to_string).collect();
+        let result = self.call(
+            "getMultipleAccounts",
+            json!([keys, { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        decode_accounts(&result["value"])
+    }
+
+    pub fn simulate(&self, tx: &Transaction, addresses: &[Pubkey]) -> Result<Simulation> {
+        let keys: Vec<String> = addresses.iter().map(Pubkey::to_string).collect();
+        let result = self.call(
+            "simulateTransaction",
+            json!([
+                encode_transaction(tx)?,
+                {
+                    "encoding": "base64",
+                    "commitment": "confirmed",
+                    "replaceRecentBlockhash": true,
+                    "accounts": { "encoding": "base64", "addresses": keys },
+                }
+            ]),
+        )?;
+
+        let value = &result["value"];
+        let error = value.get("err").filter(|err| !err.is_null()).cloned();
+        let logs = value["logs"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(|l| l.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        let accounts = if error.is_none() {
+            decode_accounts(&value["accounts"])?
+        } else {
+            vec![None; addresses.len()]
+        };
+
+        Ok(Simulation { error, logs, accounts })
+    }
+
+    // Sends the transaction and waits until it is confirmed.
+    pub fn send_and_confirm(&self, tx: &Transaction) -> Result<String> {
+        let signature = self
+            .call(
+                "sendTransaction",
+                json!([encode_transaction(tx)?, { "encoding": "base64" }]),
+            )?
+            .as_str()
+            .ok_or_else(|| anyhow!("sendTransaction returned no signature"))?
+            .to_string();
+
+        let started = Instant::now();
+        while started.elapsed() < CONFIRM_TIMEOUT {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if let Some(err) = status.get("err").filter(|err| !err.is_null()) {
+                    bail!("transaction {signature} failed: {err}");
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed") | Some("finalized")
+                ) {
+                    return Ok(signature);
+                }
+            }
+            sleep(Duration::from_millis(500));
+        }
+        bail!("transaction {signature} was not confirmed within {CONFIRM_TIMEOUT:?}")
+    }
+}
+
+fn encode_transaction(tx: &Transaction) -> Result<String> {
+    Ok(BASE64.encode(bincode::serialize(tx)?))
+}
+
+fn decode_accounts(value: &Value) -> Result<Vec<Option<AccountState>>> {
+    value
+        .as_array()
+        .ok_or_else(|| anyhow!("expected an account list"))?
+        .iter()
+        .map(|account| {
+            if account.is_null() {
+                return Ok(None);
+            }
+            let data = account["data"][0]
+                .as_str()
+                .ok_or_else(|| anyhow!("account data is not base64"))?;
+            Ok(Some(AccountState {
+                lamports: account["lamports"].as_u64().unwrap_or_default(),
+                data: BASE64.decode(data)?,
+            }))
+        })
+        .collect()
+}
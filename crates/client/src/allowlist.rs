@@ -0,0 +1,57 @@
+// crates/client/src/allowlist.rs
+
+use anchor_lang::prelude::Pubkey;
+use solana_sha256_hasher::{hash, hashv};
+
+// Matches Campaign::is_allowlisted: leaves are sha256(wallet) and
+// each parent hashes its two children smallest first. An odd node
+// out is carried up to the next level unchanged.
+
+fn leaf(wallet: &Pubkey) -> [u8; 32] {
+    hash(wallet.as_ref()).to_bytes()
+}
+
+fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&left, &right]).to_bytes()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => parent(*a, *b),
+            [a] => *a,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root to pass to create_campaign. All zeros (an open campaign) for
+/// an empty list.
+pub fn root(wallets: &[Pubkey]) -> [u8; 32] {
+    let mut level: Vec<[u8; 32]> = wallets.iter().map(leaf).collect();
+    if level.is_empty() {
+        return [0u8; 32];
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Proof for claim_campaign, or None if `wallet` is not in the list.
+pub fn proof(wallets: &[Pubkey], wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+    let mut index = wallets.iter().position(|w| w == wallet)?;
+    let mut level: Vec<[u8; 32]> = wallets.iter().map(leaf).collect();
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
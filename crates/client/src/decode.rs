@@ -0,0 +1,80 @@
+// crates/client/src/decode.rs
+
+use afrodevsols::state::*;
+use anchor_lang::prelude::*;
+
+// Deserializes any program account, checking its discriminator.
+pub fn account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+pub fn faucet_config(data: &[u8]) -> Result<FaucetConfig> {
+    account(data)
+}
+
+pub fn claimer_record(data: &[u8]) -> Result<ClaimerRecord> {
+    account(data)
+}
+
+pub fn grant_record(data: &[u8]) -> Result<GrantRecord> {
+    account(data)
+}
+
+pub fn grant_milestones(data: &[u8]) -> Result<GrantMilestones> {
+    account(data)
+}
+
+pub fn referral_record(data: &[u8]) -> Result<ReferralRecord> {
+    account(data)
+}
+
+pub fn referral_code(data: &[u8]) -> Result<ReferralCode> {
+    account(data)
+}
+
+pub fn daily_stats(data: &[u8]) -> Result<DailyStats> {
+    account(data)
+}
+
+pub fn funder_record(data: &[u8]) -> Result<FunderRecord> {
+    account(data)
+}
+
+pub fn campaign(data: &[u8]) -> Result<Campaign> {
+    account(data)
+}
+
+pub fn campaign_claim_record(data: &[u8]) -> Result<CampaignClaimRecord> {
+    account(data)
+}
+
+pub fn voucher(data: &[u8]) -> Result<Voucher> {
+    account(data)
+}
+
+pub fn voucher_redemption(data: &[u8]) -> Result<VoucherRedemption> {
+    account(data)
+}
+
+pub fn bounty(data: &[u8]) -> Result<Bounty> {
+    account(data)
+}
+
+pub fn bounty_submission(data: &[u8]) -> Result<BountySubmission> {
+    account(data)
+}
+
+pub fn leaderboard(data: &[u8]) -> Result<Leaderboard> {
+    account(data)
+}
+
+// check_eligibility return data (plain borsh, no discriminator)
+pub fn eligibility_result(return_data: &[u8]) -> Result<EligibilityResult> {
+    Ok(EligibilityResult::try_from_slice(return_data)?)
+}
+
+// cpi_claim return data (plain borsh, no discriminator)
+pub fn cpi_claim_result(return_data: &[u8]) -> Result<CpiClaimResult> {
+    Ok(CpiClaimResult::try_from_slice(return_data)?)
+}
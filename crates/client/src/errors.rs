@@ -0,0 +1,112 @@
+// crates/client/src/errors.rs
+
+use afrodevsols::errors::AfrodevsError;
+
+// Declaration order, so ALL[i] has code ERROR_CODE_OFFSET + i.
+// Keep in sync with programs/afrodevsols/src/errors.rs.
+pub const ALL: &[AfrodevsError] = &[
+    AfrodevsError::FaucetPaused,
+    AfrodevsError::InsufficientTreasury,
+    AfrodevsError::CooldownActive,
+    AfrodevsError::WalletBlocked,
+    AfrodevsError::DailyLimitReached,
+    AfrodevsError::InvalidAmount,
+    AfrodevsError::AmountTooLow,
+    AfrodevsError::AmountTooHigh,
+    AfrodevsError::Unauthorized,
+    AfrodevsError::InvalidReferral,
+    AfrodevsError::SelfReferral,
+    AfrodevsError::ReferralAlreadyUsed,
+    AfrodevsError::NoPendingBonus,
+    AfrodevsError::RentReserveViolation,
+    AfrodevsError::ReferralDisabled,
+    AfrodevsError::EmptyRecipientList,
+    AfrodevsError::RecipientAmountMismatch,
+    AfrodevsError::TooManyRecipients,
+    AfrodevsError::EmptyReason,
+    AfrodevsError::BatchTooLarge,
+    AfrodevsError::Overflow,
+    AfrodevsError::InvalidGrantExpiry,
+    AfrodevsError::GrantNotPending,
+    AfrodevsError::GrantExpired,
+    AfrodevsError::GrantNotExpired,
+    AfrodevsError::InvalidMilestones,
+    AfrodevsError::InvalidMilestoneIndex,
+    AfrodevsError::MilestoneAlreadyReleased,
+    AfrodevsError::GrantNotInProgress,
+    AfrodevsError::InvalidReferralCode,
+    AfrodevsError::ReferralRecordRequired,
+    AfrodevsError::InvalidBasisPoints,
+    AfrodevsError::TooManyReferralLevels,
+    AfrodevsError::ReferralCycle,
+    AfrodevsError::ReferralDailyCapReached,
+    AfrodevsError::ReferralLifetimeCapReached,
+    AfrodevsError::ReferralBonusExpired,
+    AfrodevsError::ReferralBonusNotExpired,
+    AfrodevsError::LiabilityReserveViolation,
+    AfrodevsError::InvalidLeaderboardKind,
+    AfrodevsError::NameTooLong,
+    AfrodevsError::InvalidWaterMarks,
+    AfrodevsError::InvalidScalingConfig,
+    AfrodevsError::SponsoredClaimsDisabled,
+    AfrodevsError::SponsoredRecordMissing,
+    AfrodevsError::UnauthorizedIntegrator,
+    AfrodevsError::IntegratorListFull,
+    AfrodevsError::InvalidIntegrator,
+    AfrodevsError::TierPaused,
+    AfrodevsError::ReferralPayoutPaused,
+    AfrodevsError::GrantsPaused,
+    AfrodevsError::WithdrawalsPaused,
+    AfrodevsError::InvalidPauseFeatures,
+    AfrodevsError::InvalidCircuitBreakerWindow,
+    AfrodevsError::InvalidCampaign,
+    AfrodevsError::CampaignNotOpen,
+    AfrodevsError::NotOnCampaignAllowlist,
+    AfrodevsError::CampaignWalletLimitReached,
+    AfrodevsError::CampaignBudgetExhausted,
+    AfrodevsError::CampaignNotFinished,
+    AfrodevsError::InvalidVoucher,
+    AfrodevsError::InvalidVoucherCode,
+    AfrodevsError::VoucherExpired,
+    AfrodevsError::VoucherUsedUp,
+    AfrodevsError::VoucherAlreadyRedeemed,
+    AfrodevsError::VoucherStillActive,
+    AfrodevsError::InvalidBountyDeadline,
+    AfrodevsError::BountyNotOpen,
+    AfrodevsError::BountyNotExpired,
+    AfrodevsError::TierLocked,
+    AfrodevsError::InvalidTierRequirements,
+    AfrodevsError::GrantNotSettled,
+    AfrodevsError::GrantMilestonesRequired,
+    AfrodevsError::CampaignNotSwept,
+    AfrodevsError::BountyStillOpen,
+    AfrodevsError::LeaderboardRequired,
+    AfrodevsError::InvalidAccountLayout,
+    AfrodevsError::AccountAlreadyMigrated,
+];
+
+/// Maps a custom program error code (6000 and up) to its variant.
+pub fn decode_error(code: u32) -> Option<AfrodevsError> {
+    let index = code.checked_sub(anchor_lang::error::ERROR_CODE_OFFSET)?;
+    ALL.get(index as usize).copied()
+}
+
+/// Finds the program error in an RPC error string or log line, e.g.
+/// "custom program error: 0x1770" or "Error Number: 6000.".
+pub fn decode_error_message(message: &str) -> Option<AfrodevsError> {
+    if let Some(start) = message.find("custom program error: 0x") {
+        let hex: String = message[start + 24..]
+            .chars()
+            .take_while(|c| c.is_ascii_hexdigit())
+            .collect();
+        return u32::from_str_radix(&hex, 16).ok().and_then(decode_error);
+    }
+    if let Some(start) = message.find("Error Number: ") {
+        let digits: String = message[start + 14..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        return digits.parse().ok().and_then(decode_error);
+    }
+    None
+}
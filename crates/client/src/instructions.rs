@@ -0,0 +1,808 @@
+// crates/client/src/instructions.rs
+
+use afrodevsols::constants::*;
+use afrodevsols::state::Voucher;
+use afrodevsols::{accounts, instruction, ID};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_lang::InstructionData;
+use solana_ed25519_program::new_ed25519_instruction_with_signature;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// ============================================================
+// SETUP & FUNDING
+// ============================================================
+
+pub fn initialize(authority: Pubkey, args: instruction::Initialize) -> Instruction {
+    build(
+        accounts::Initialize {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+// `update_leaderboard` is required once the donors board is initialized.
+pub fn fund_treasury(funder: Pubkey, amount: u64, update_leaderboard: bool) -> Instruction {
+    build(
+        accounts::FundTreasury {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            funder_record: pda::funder_record(&funder).0,
+            donors_leaderboard: update_leaderboard
+                .then(|| pda::leaderboard(LEADERBOARD_KIND_DONORS).0),
+            funder,
+            system_program: system_program::ID,
+        },
+        instruction::FundTreasury { amount },
+    )
+}
+
+pub fn set_funder_display_name(funder: Pubkey, display_name: String) -> Instruction {
+    build(
+        accounts::SetFunderDisplayName {
+            funder_record: pda::funder_record(&funder).0,
+            funder,
+        },
+        instruction::SetFunderDisplayName { display_name },
+    )
+}
+
+// ============================================================
+// CLAIMS
+// ============================================================
+
+// Optional accounts for the claim family of instructions.
+#[derive(Clone, Debug, Default)]
+pub struct ClaimOptions {
+    /// Referrer to register. Only honoured on a wallet's first claim.
+    pub referrer: Option<Pubkey>,
+    /// Wallet that referred the claimer. Passes the ReferralRecord and
+    /// the referrer's ClaimerRecord; needed on every claim by a referred
+    /// wallet. Defaults to `referrer`.
+    pub referred_by: Option<Pubkey>,
+    /// Pass the leaderboards. Required once the board is initialized,
+    /// see FaucetConfig::has_leaderboard.
+    pub update_claimers_leaderboard: bool,
+    pub update_referrers_leaderboard: bool,
+    /// Wallets above the referrer, nearest first, for multi-level bonuses.
+    /// Required up to FaucetConfig.referral_ancestor_levels or the top of
+    /// the chain, whichever comes first, when the referral unlocks.
+    pub ancestors: Vec<Pubkey>,
+}
+
+fn claim_accounts(claimer: Pubkey, options: &ClaimOptions) -> accounts::Claim {
+    let referred_by = options.referred_by.or(options.referrer);
+
+    accounts::Claim {
+        faucet_config: pda::faucet_config().0,
+        treasury_vault: pda::treasury_vault().0,
+        claimer_record: pda::claimer_record(&claimer).0,
+        referral_record: referred_by.map(|_| pda::referral_record(&claimer).0),
+        referrer_record: referred_by.map(|referrer| pda::claimer_record(&referrer).0),
+        claimers_leaderboard: options
+            .update_claimers_leaderboard
+            .then(|| pda::leaderboard(LEADERBOARD_KIND_CLAIMERS).0),
+        referrers_leaderboard: options
+            .update_referrers_leaderboard
+            .then(|| pda::leaderboard(LEADERBOARD_KIND_REFERRERS).0),
+        claimer,
+        system_program: system_program::ID,
+    }
+}
+
+fn with_ancestors(mut ix: Instruction, options: &ClaimOptions) -> Instruction {
+    ix.accounts.extend(
+        options
+            .ancestors
+            .iter()
+            .map(|ancestor| AccountMeta::new(pda::claimer_record(ancestor).0, false)),
+    );
+    ix
+}
+
+pub fn claim(claimer: Pubkey, amount: u64, options: &ClaimOptions) -> Instruction {
+    let ix = build(
+        claim_accounts(claimer, options),
+        instruction::Claim {
+            amount,
+            referrer: options.referrer,
+        },
+    );
+    with_ancestors(ix, options)
+}
+
+// `options.referred_by` should be the code's current owner.
+pub fn claim_with_code(
+    claimer: Pubkey,
+    amount: u64,
+    code: String,
+    options: &ClaimOptions,
+) -> Instruction {
+    let ix = build(
+        accounts::ClaimWithCode {
+            claim: claim_accounts(claimer, options),
+            referral_code: pda::referral_code(&code).0,
+        },
+        instruction::ClaimWithCode { amount, code },
+    );
+    with_ancestors(ix, options)
+}
+
+pub fn sponsor_claimer_record(claimer: Pubkey, relayer: Pubkey, with_referral: bool) -> Instruction {
+    build(
+        accounts::SponsorClaimerRecord {
+            faucet_config: pda::faucet_config().0,
+            claimer_record: pda::claimer_record(&claimer).0,
+            referral_record: with_referral.then(|| pda::referral_record(&claimer).0),
+            claimer,
+            relayer,
+            system_program: system_program::ID,
+        },
+        instruction::SponsorClaimerRecord {},
+    )
+}
+
+pub fn sponsored_claim(
+    claimer: Pubkey,
+    relayer: Pubkey,
+    amount: u64,
+    options: &ClaimOptions,
+) -> Instruction {
+    let ix = build(
+        accounts::SponsoredClaim {
+            claim: claim_accounts(claimer, options),
+            relayer,
+        },
+        instruction::SponsoredClaim {
+            amount,
+            referrer: options.referrer,
+        },
+    );
+    with_ancestors(ix, options)
+}
+
+// For integrator programs: the accounts to pass through when
+// invoking cpi_claim, signing for `claimer` with invoke_signed.
+// Decode the return data with decode::cpi_claim_result. Set
+// `update_claimers_leaderboard` once that board is initialized.
+pub fn cpi_claim(
+    claimer: Pubkey,
+    destination: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+    update_claimers_leaderboard: bool,
+) -> Instruction {
+    build(
+        accounts::CpiClaim {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            claimer_record: pda::claimer_record(&claimer).0,
+            claimers_leaderboard: update_claimers_leaderboard
+                .then(|| pda::leaderboard(LEADERBOARD_KIND_CLAIMERS).0),
+            claimer,
+            destination,
+            payer,
+            instructions: solana_instructions_sysvar::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CpiClaim { amount },
+    )
+}
+
+// Simulate this and decode the return data with
+// decode::eligibility_result.
+pub fn check_eligibility(wallet: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::CheckEligibility {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            claimer_record: pda::claimer_record(&wallet).0,
+        },
+        instruction::CheckEligibility { wallet, amount },
+    )
+}
+
+// ============================================================
+// REFERRALS
+// ============================================================
+
+pub fn claim_referral_bonus(referrer: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimReferralBonus {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            referrer_record: pda::claimer_record(&referrer).0,
+            referrer,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimReferralBonus {},
+    )
+}
+
+pub fn register_referral_code(owner: Pubkey, code: String) -> Instruction {
+    build(
+        accounts::RegisterReferralCode {
+            referral_code: pda::referral_code(&code).0,
+            owner,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterReferralCode { code },
+    )
+}
+
+pub fn transfer_referral_code(owner: Pubkey, code: String, new_owner: Pubkey) -> Instruction {
+    build(
+        accounts::TransferReferralCode {
+            referral_code: pda::referral_code(&code).0,
+            owner,
+        },
+        instruction::TransferReferralCode { code, new_owner },
+    )
+}
+
+pub fn release_referral_code(owner: Pubkey, code: String) -> Instruction {
+    build(
+        accounts::ReleaseReferralCode {
+            referral_code: pda::referral_code(&code).0,
+            owner,
+        },
+        instruction::ReleaseReferralCode { code },
+    )
+}
+
+pub fn expire_referral_bonus(cranker: Pubkey, target_wallet: Pubkey) -> Instruction {
+    build(
+        accounts::ExpireReferralBonus {
+            faucet_config: pda::faucet_config().0,
+            referrer_record: pda::claimer_record(&target_wallet).0,
+            cranker,
+        },
+        instruction::ExpireReferralBonus { target_wallet },
+    )
+}
+
+// ============================================================
+// GRANTS
+// `nonce` is FaucetConfig.grant_nonce when the grant is sent.
+// ============================================================
+
+pub fn special_grant(
+    authority: Pubkey,
+    recipient: Pubkey,
+    nonce: u64,
+    amount: u64,
+    reason: String,
+    is_public: bool,
+) -> Instruction {
+    build(
+        accounts::SpecialGrant {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            recipient_wallet: recipient,
+            grant_record: pda::grant_record(&authority, &recipient, nonce).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::SpecialGrant {
+            recipient,
+            amount,
+            reason,
+            is_public,
+        },
+    )
+}
+
+pub fn escrow_grant(
+    authority: Pubkey,
+    recipient: Pubkey,
+    nonce: u64,
+    amount: u64,
+    reason: String,
+    is_public: bool,
+    expires_in: i64,
+) -> Instruction {
+    build(
+        accounts::EscrowGrant {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            grant_record: pda::grant_record(&authority, &recipient, nonce).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::EscrowGrant {
+            recipient,
+            amount,
+            reason,
+            is_public,
+            expires_in,
+        },
+    )
+}
+
+pub fn accept_grant(grant_record: Pubkey, recipient: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptGrant {
+            grant_record,
+            recipient,
+        },
+        instruction::AcceptGrant {},
+    )
+}
+
+pub fn expire_grant(grant_record: Pubkey, cranker: Pubkey) -> Instruction {
+    build(
+        accounts::ExpireGrant {
+            grant_record,
+            treasury_vault: pda::treasury_vault().0,
+            cranker,
+        },
+        instruction::ExpireGrant {},
+    )
+}
+
+pub fn create_milestone_grant(
+    authority: Pubkey,
+    recipient: Pubkey,
+    nonce: u64,
+    reason: String,
+    is_public: bool,
+    milestone_amounts: Vec<u64>,
+    description_hashes: Vec<[u8; 32]>,
+) -> Instruction {
+    let grant_record = pda::grant_record(&authority, &recipient, nonce).0;
+    build(
+        accounts::CreateMilestoneGrant {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            grant_record,
+            grant_milestones: pda::grant_milestones(&grant_record).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMilestoneGrant {
+            recipient,
+            reason,
+            is_public,
+            milestone_amounts,
+            description_hashes,
+        },
+    )
+}
+
+pub fn approve_milestone(
+    authority: Pubkey,
+    grant_record: Pubkey,
+    recipient: Pubkey,
+    milestone_index: u8,
+) -> Instruction {
+    build(
+        accounts::ApproveMilestone {
+            faucet_config: pda::faucet_config().0,
+            grant_record,
+            grant_milestones: pda::grant_milestones(&grant_record).0,
+            recipient_wallet: recipient,
+            authority,
+        },
+        instruction::ApproveMilestone { milestone_index },
+    )
+}
+
+pub fn cancel_milestone_grant(authority: Pubkey, grant_record: Pubkey) -> Instruction {
+    build(
+        accounts::CancelMilestoneGrant {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            grant_record,
+            grant_milestones: pda::grant_milestones(&grant_record).0,
+            authority,
+        },
+        instruction::CancelMilestoneGrant {},
+    )
+}
+
+// `is_milestone` must match the grant's type; the schedule is closed with it.
+pub fn close_grant_record(authority: Pubkey, grant_record: Pubkey, is_milestone: bool) -> Instruction {
+    build(
+        accounts::CloseGrantRecord {
+            faucet_config: pda::faucet_config().0,
+            grant_record,
+            grant_milestones: is_milestone.then(|| pda::grant_milestones(&grant_record).0),
+            authority,
+        },
+        instruction::CloseGrantRecord {},
+    )
+}
+
+// ============================================================
+// CAMPAIGNS
+// ============================================================
+
+pub fn create_campaign(authority: Pubkey, args: instruction::CreateCampaign) -> Instruction {
+    build(
+        accounts::CreateCampaign {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            campaign: pda::campaign(args.campaign_id).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+// `allowlist_proof` comes from allowlist::proof; empty for an open
+// campaign.
+pub fn claim_campaign(
+    claimer: Pubkey,
+    campaign_id: u64,
+    amount: u64,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let campaign = pda::campaign(campaign_id).0;
+    build(
+        accounts::ClaimCampaign {
+            faucet_config: pda::faucet_config().0,
+            campaign,
+            campaign_claim_record: pda::campaign_claim_record(&campaign, &claimer).0,
+            claimer_record: pda::claimer_record(&claimer).0,
+            claimer,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimCampaign { amount, allowlist_proof },
+    )
+}
+
+pub fn close_campaign(authority: Pubkey, campaign_id: u64) -> Instruction {
+    build(
+        accounts::CloseCampaign {
+            faucet_config: pda::faucet_config().0,
+            campaign: pda::campaign(campaign_id).0,
+            authority,
+        },
+        instruction::CloseCampaign {},
+    )
+}
+
+pub fn sweep_campaign(campaign_id: u64, cranker: Pubkey) -> Instruction {
+    build(
+        accounts::SweepCampaign {
+            campaign: pda::campaign(campaign_id).0,
+            treasury_vault: pda::treasury_vault().0,
+            cranker,
+        },
+        instruction::SweepCampaign {},
+    )
+}
+
+pub fn close_campaign_account(authority: Pubkey, campaign_id: u64) -> Instruction {
+    build(
+        accounts::CloseCampaignAccount {
+            faucet_config: pda::faucet_config().0,
+            campaign: pda::campaign(campaign_id).0,
+            authority,
+        },
+        instruction::CloseCampaignAccount {},
+    )
+}
+
+// ============================================================
+// VOUCHERS
+// ============================================================
+
+// The keypair a voucher code stands for. Its public key names the
+// voucher on chain; the code itself never leaves the client.
+pub fn voucher_keypair(code: &str) -> Keypair {
+    Keypair::new_from_array(Voucher::hash_code(code))
+}
+
+pub fn create_voucher(
+    authority: Pubkey,
+    code: &str,
+    amount: u64,
+    expires_at: i64,
+    max_uses: u32,
+) -> Instruction {
+    let code_key = voucher_keypair(code).pubkey();
+    build(
+        accounts::CreateVoucher {
+            faucet_config: pda::faucet_config().0,
+            voucher: pda::voucher(&code_key).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::CreateVoucher { code_key, amount, expires_at, max_uses },
+    )
+}
+
+// Signs the redemption with the code's key. Send both instructions,
+// in this order, in one transaction.
+pub fn redeem_voucher(redeemer: Pubkey, code: &str) -> [Instruction; 2] {
+    let code_keypair = voucher_keypair(code);
+    let voucher = pda::voucher(&code_keypair.pubkey()).0;
+    let message = Voucher::redemption_message(&voucher, &redeemer);
+    let signature = code_keypair.sign_message(&message);
+
+    [
+        new_ed25519_instruction_with_signature(
+            &message,
+            &signature.into(),
+            &code_keypair.pubkey().to_bytes(),
+        ),
+        build(
+            accounts::RedeemVoucher {
+                faucet_config: pda::faucet_config().0,
+                treasury_vault: pda::treasury_vault().0,
+                voucher,
+                voucher_redemption: pda::voucher_redemption(&voucher, &redeemer).0,
+                claimer_record: pda::claimer_record(&redeemer).0,
+                redeemer,
+                instructions: solana_instructions_sysvar::ID,
+                system_program: system_program::ID,
+            },
+            instruction::RedeemVoucher {},
+        ),
+    ]
+}
+
+pub fn close_voucher(authority: Pubkey, code_key: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseVoucher {
+            faucet_config: pda::faucet_config().0,
+            voucher: pda::voucher(code_key).0,
+            authority,
+        },
+        instruction::CloseVoucher {},
+    )
+}
+
+// ============================================================
+// BOUNTIES
+// `bounty_id` for a new bounty is FaucetConfig.bounty_nonce.
+// ============================================================
+
+pub fn create_bounty(authority: Pubkey, bounty_id: u64, args: instruction::CreateBounty) -> Instruction {
+    build(
+        accounts::CreateBounty {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            bounty: pda::bounty(bounty_id).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn submit_bounty(submitter: Pubkey, bounty_id: u64, work_uri_hash: [u8; 32]) -> Instruction {
+    let bounty = pda::bounty(bounty_id).0;
+    build(
+        accounts::SubmitBounty {
+            bounty,
+            submission: pda::bounty_submission(&bounty, &submitter).0,
+            claimer_record: pda::claimer_record(&submitter).0,
+            submitter,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitBounty { work_uri_hash },
+    )
+}
+
+pub fn award_bounty(authority: Pubkey, bounty_id: u64, winner: Pubkey) -> Instruction {
+    let bounty = pda::bounty(bounty_id).0;
+    build(
+        accounts::AwardBounty {
+            faucet_config: pda::faucet_config().0,
+            bounty,
+            submission: pda::bounty_submission(&bounty, &winner).0,
+            winner,
+            authority,
+        },
+        instruction::AwardBounty {},
+    )
+}
+
+pub fn expire_bounty(bounty_id: u64, cranker: Pubkey) -> Instruction {
+    build(
+        accounts::ExpireBounty {
+            bounty: pda::bounty(bounty_id).0,
+            treasury_vault: pda::treasury_vault().0,
+            cranker,
+        },
+        instruction::ExpireBounty {},
+    )
+}
+
+pub fn close_bounty(authority: Pubkey, bounty_id: u64) -> Instruction {
+    build(
+        accounts::CloseBounty {
+            faucet_config: pda::faucet_config().0,
+            bounty: pda::bounty(bounty_id).0,
+            authority,
+        },
+        instruction::CloseBounty {},
+    )
+}
+
+// ============================================================
+// ADMIN
+// ============================================================
+
+fn update_config_accounts(authority: Pubkey) -> accounts::UpdateConfig {
+    accounts::UpdateConfig {
+        faucet_config: pda::faucet_config().0,
+        authority,
+    }
+}
+
+pub fn update_config(authority: Pubkey, args: instruction::UpdateConfig) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_referral_config(
+    authority: Pubkey,
+    args: instruction::UpdateReferralConfig,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_referral_limits(
+    authority: Pubkey,
+    args: instruction::UpdateReferralLimits,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_streak_config(authority: Pubkey, args: instruction::UpdateStreakConfig) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_tier_requirements(
+    authority: Pubkey,
+    args: instruction::UpdateTierRequirements,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_treasury_alerts(
+    authority: Pubkey,
+    args: instruction::UpdateTreasuryAlerts,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_runway_scaling(
+    authority: Pubkey,
+    args: instruction::UpdateRunwayScaling,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_sponsor_config(
+    authority: Pubkey,
+    args: instruction::UpdateSponsorConfig,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn update_circuit_breaker(
+    authority: Pubkey,
+    args: instruction::UpdateCircuitBreaker,
+) -> Instruction {
+    build(update_config_accounts(authority), args)
+}
+
+pub fn reset_circuit_breaker(authority: Pubkey) -> Instruction {
+    build(update_config_accounts(authority), instruction::ResetCircuitBreaker {})
+}
+
+// `features` is a mask of PAUSE_* bits. `reason` is ignored when
+// resuming.
+pub fn set_paused(authority: Pubkey, features: u16, paused: bool, reason: u8) -> Instruction {
+    build(
+        update_config_accounts(authority),
+        instruction::SetPaused { features, paused, reason },
+    )
+}
+
+pub fn set_integrator(authority: Pubkey, program_id: Pubkey, allowed: bool) -> Instruction {
+    build(
+        update_config_accounts(authority),
+        instruction::SetIntegrator { program_id, allowed },
+    )
+}
+
+pub fn initialize_leaderboard(authority: Pubkey, kind: u8) -> Instruction {
+    build(
+        accounts::InitializeLeaderboard {
+            faucet_config: pda::faucet_config().0,
+            leaderboard: pda::leaderboard(kind).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeLeaderboard { kind },
+    )
+}
+
+pub fn block_wallet(authority: Pubkey, target_wallet: Pubkey, block: bool) -> Instruction {
+    build(
+        accounts::BlockWallet {
+            faucet_config: pda::faucet_config().0,
+            claimer_record: pda::claimer_record(&target_wallet).0,
+            authority,
+        },
+        instruction::BlockWallet {
+            target_wallet,
+            block,
+        },
+    )
+}
+
+pub fn withdraw_treasury(authority: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            faucet_config: pda::faucet_config().0,
+            treasury_vault: pda::treasury_vault().0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawTreasury { amount },
+    )
+}
+
+// `signer` is the admin or the record's own wallet.
+pub fn close_claimer_record(
+    signer: Pubkey,
+    target_wallet: Pubkey,
+    rent_receiver: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseClaimerRecord {
+            faucet_config: pda::faucet_config().0,
+            claimer_record: pda::claimer_record(&target_wallet).0,
+            rent_receiver,
+            signer,
+        },
+        instruction::CloseClaimerRecord { target_wallet },
+    )
+}
+
+pub fn migrate_config(authority: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateConfig {
+            faucet_config: pda::faucet_config().0,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateConfig {},
+    )
+}
+
+// `record` is any ClaimerRecord, ReferralRecord or GrantRecord
+// still in its original, shorter layout.
+pub fn migrate_record(payer: Pubkey, record: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateRecord {
+            faucet_config: pda::faucet_config().0,
+            record,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateRecord {},
+    )
+}
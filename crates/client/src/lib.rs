@@ -0,0 +1,26 @@
+// crates/client/src/lib.rs
+
+//! Rust client for the afrodevsols faucet program.
+//!
+//! - `allowlist`: builds campaign allowlist Merkle roots and proofs
+//! - `pda`: derives every program address from the seeds in `constants.rs`
+//! - `instructions`: one builder per program instruction
+//! - `decode`: deserializes program accounts from raw account data
+//! - `errors`: maps custom error codes back to `AfrodevsError`
+
+pub mod allowlist;
+pub mod decode;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+
+pub use afrodevsols::constants;
+pub use afrodevsols::errors::AfrodevsError;
+pub use afrodevsols::state;
+pub use afrodevsols::ID;
+
+// Argument structs for the long, all-optional admin instructions
+pub use afrodevsols::instruction as args;
+
+pub use anchor_lang::prelude::Pubkey;
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
@@ -0,0 +1,84 @@
+// crates/client/src/pda.rs
+
+use afrodevsols::constants::*;
+use afrodevsols::ID;
+use anchor_lang::prelude::Pubkey;
+
+// Every function returns (address, bump) like find_program_address.
+
+pub fn faucet_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FAUCET_CONFIG_SEED], &ID)
+}
+
+pub fn treasury_vault() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_VAULT_SEED], &ID)
+}
+
+pub fn claimer_record(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIMER_SEED, wallet.as_ref()], &ID)
+}
+
+// Keyed by the referred wallet, not the referrer
+pub fn referral_record(referred: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_SEED, referred.as_ref()], &ID)
+}
+
+// `nonce` is FaucetConfig.grant_nonce at the time the grant is made
+pub fn grant_record(authority: &Pubkey, recipient: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            GRANT_RECORD_SEED,
+            authority.as_ref(),
+            recipient.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn grant_milestones(grant_record: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GRANT_MILESTONES_SEED, grant_record.as_ref()], &ID)
+}
+
+// `day` is DailyStats::day_number(timestamp)
+pub fn daily_stats(day: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DAILY_STATS_SEED, &day.to_le_bytes()], &ID)
+}
+
+pub fn referral_code(code: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRAL_CODE_SEED, code.as_bytes()], &ID)
+}
+
+// `kind` is one of the LEADERBOARD_KIND_* constants
+pub fn leaderboard(kind: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LEADERBOARD_SEED, &[kind]], &ID)
+}
+
+pub fn funder_record(funder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FUNDER_SEED, funder.as_ref()], &ID)
+}
+
+pub fn campaign(campaign_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CAMPAIGN_SEED, &campaign_id.to_le_bytes()], &ID)
+}
+
+pub fn campaign_claim_record(campaign: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CAMPAIGN_CLAIM_SEED, campaign.as_ref(), wallet.as_ref()], &ID)
+}
+
+// `code_key` is instructions::voucher_keypair(code).pubkey()
+pub fn voucher(code_key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOUCHER_SEED, code_key.as_ref()], &ID)
+}
+
+pub fn voucher_redemption(voucher: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOUCHER_REDEMPTION_SEED, voucher.as_ref(), wallet.as_ref()], &ID)
+}
+
+pub fn bounty(bounty_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOUNTY_SEED, &bounty_id.to_le_bytes()], &ID)
+}
+
+pub fn bounty_submission(bounty: &Pubkey, submitter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOUNTY_SUBMISSION_SEED, bounty.as_ref(), submitter.as_ref()], &ID)
+}
@@ -0,0 +1,619 @@
+// crates/client/tests/client.rs
+
+use afrodevsols::state::{Campaign, ClaimerRecord, FaucetConfig, FaucetConfigV0, Voucher};
+use afrodevsols_client::{
+    allowlist, args, decode, errors, instructions, pda, AfrodevsError, Instruction, Pubkey, ID,
+};
+use anchor_lang::prelude::AccountInfo;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::{AccountSerialize, AnchorSerialize, Discriminator};
+use solana_account::Account;
+use solana_keypair::Keypair;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+const SOL: u64 = 1_000_000_000;
+
+#[test]
+fn pdas_match_raw_seeds() {
+    let wallet = Pubkey::new_unique();
+
+    assert_eq!(
+        pda::faucet_config().0,
+        Pubkey::find_program_address(&[b"faucet_config"], &ID).0
+    );
+    assert_eq!(
+        pda::claimer_record(&wallet).0,
+        Pubkey::find_program_address(&[b"claimer", wallet.as_ref()], &ID).0
+    );
+    assert_eq!(
+        pda::leaderboard(2).0,
+        Pubkey::find_program_address(&[b"leaderboard", &[2]], &ID).0
+    );
+}
+
+#[test]
+fn claim_builder_orders_accounts_and_ancestors() {
+    let claimer = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+    let ancestor = Pubkey::new_unique();
+
+    let ix = instructions::claim(
+        claimer,
+        100_000_000,
+        &instructions::ClaimOptions {
+            referrer: Some(referrer),
+            ancestors: vec![ancestor],
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(ix.program_id, ID);
+    assert_eq!(&ix.data[..8], afrodevsols::instruction::Claim::DISCRIMINATOR);
+
+    // 9 named accounts, then the ancestor's ClaimerRecord
+    assert_eq!(ix.accounts.len(), 10);
+    assert_eq!(ix.accounts[2].pubkey, pda::claimer_record(&claimer).0);
+    assert_eq!(ix.accounts[3].pubkey, pda::referral_record(&claimer).0);
+    assert_eq!(ix.accounts[4].pubkey, pda::claimer_record(&referrer).0);
+    assert_eq!(ix.accounts[5].pubkey, ID, "Unused optional account");
+    assert!(ix.accounts[7].is_signer);
+    assert_eq!(ix.accounts[9].pubkey, pda::claimer_record(&ancestor).0);
+    assert!(ix.accounts[9].is_writable);
+}
+
+#[test]
+fn sponsored_claim_marks_both_signers() {
+    let claimer = Pubkey::new_unique();
+    let relayer = Pubkey::new_unique();
+
+    let ix = instructions::sponsored_claim(claimer, relayer, 1, &Default::default());
+    let signers: Vec<Pubkey> = ix
+        .accounts
+        .iter()
+        .filter(|meta| meta.is_signer)
+        .map(|meta| meta.pubkey)
+        .collect();
+
+    assert_eq!(signers, vec![claimer, relayer]);
+}
+
+#[test]
+fn voucher_redemption_is_signed_for_the_redeemer() {
+    let redeemer = Pubkey::new_unique();
+    let code_key = instructions::voucher_keypair("lagos-meetup").pubkey();
+    let voucher = pda::voucher(&code_key).0;
+
+    let [sigverify, redeem] = instructions::redeem_voucher(redeemer, "lagos-meetup");
+
+    // Public key and message sit inline after the 16-byte header
+    let message = Voucher::redemption_message(&voucher, &redeemer);
+    assert_eq!(&sigverify.data[16..48], code_key.as_ref());
+    assert_eq!(&sigverify.data[sigverify.data.len() - 64..], &message[..]);
+
+    assert_eq!(redeem.accounts[2].pubkey, voucher);
+    assert_eq!(redeem.accounts[3].pubkey, pda::voucher_redemption(&voucher, &redeemer).0);
+}
+
+#[test]
+fn decodes_accounts_and_rejects_wrong_type() {
+    let mut data = FaucetConfig::DISCRIMINATOR.to_vec();
+    data.resize(FaucetConfig::LEN, 0);
+
+    let config = decode::faucet_config(&data).unwrap();
+    assert_eq!(config.total_claims, 0);
+
+    let mut round_trip = Vec::new();
+    config.try_serialize(&mut round_trip).unwrap();
+    assert_eq!(round_trip, data);
+
+    assert!(decode::claimer_record(&data).is_err());
+
+    let mut data = ClaimerRecord::DISCRIMINATOR.to_vec();
+    data.resize(ClaimerRecord::LEN, 0);
+    assert!(decode::claimer_record(&data).unwrap().referred_by.is_none());
+}
+
+#[test]
+fn decodes_every_error_code() {
+    for (index, error) in errors::ALL.iter().enumerate() {
+        let code = u32::from(*error);
+        assert_eq!(code, 6000 + index as u32, "{} is out of order", error.name());
+        assert_eq!(errors::decode_error(code).map(u32::from), Some(code));
+    }
+    assert!(errors::decode_error(6000 + errors::ALL.len() as u32).is_none());
+    assert!(errors::decode_error(42).is_none());
+
+    assert!(matches!(
+        errors::decode_error_message("custom program error: 0x1770"),
+        Some(AfrodevsError::FaucetPaused)
+    ));
+    assert!(matches!(
+        errors::decode_error_message("Error Code: CooldownActive. Error Number: 6002."),
+        Some(AfrodevsError::CooldownActive)
+    ));
+}
+
+#[test]
+fn allowlist_proofs_verify_on_chain() {
+    let wallets: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    let campaign = Campaign {
+        campaign_id: 1,
+        authority: Pubkey::new_unique(),
+        name: [0u8; 30],
+        budget: 0,
+        starts_at: 0,
+        ends_at: 0,
+        tier_amounts: [0; 4],
+        cooldown_seconds: 0,
+        per_wallet_limit: 0,
+        allowlist_root: allowlist::root(&wallets),
+        status: 0,
+        total_claimed: 0,
+        claim_count: 0,
+        unique_claimers: 0,
+        swept_amount: 0,
+        created_at: 0,
+        closed_at: 0,
+        bump: 0,
+    };
+
+    // Five leaves leave an odd node out at two levels
+    for wallet in &wallets {
+        let proof = allowlist::proof(&wallets, wallet).unwrap();
+        assert!(campaign.is_allowlisted(wallet, &proof));
+    }
+
+    let outsider = Pubkey::new_unique();
+    assert!(allowlist::proof(&wallets, &outsider).is_none());
+    let borrowed = allowlist::proof(&wallets, &wallets[0]).unwrap();
+    assert!(!campaign.is_allowlisted(&outsider, &borrowed));
+    assert_eq!(allowlist::root(&[]), [0u8; 32], "Empty list is an open campaign");
+}
+
+// ============================================================
+// IN-PROCESS SVM
+// Runs the program as a native builtin inside solana-program-test,
+// so no `anchor build` is needed. Its CPIs reach the bank through
+// the patched solana-invoke in patches/.
+// ============================================================
+
+// Anchor's entry ties the account slice to the accounts' lifetime,
+// which the builtin signature doesn't. Leaking a copy per call is
+// fine for a test bank.
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts: &[AccountInfo] = Box::leak(accounts.to_vec().into_boxed_slice());
+    afrodevsols::entry(program_id, accounts, data)
+}
+
+// Starts a bank with the program, `wallets` funded with 10 SOL each
+// and any preset program `accounts`.
+async fn start(wallets: &[&Keypair], accounts: Vec<(Pubkey, Account)>) -> ProgramTestContext {
+    let mut test = ProgramTest::new("afrodevsols", ID, processor!(process_instruction));
+    test.prefer_bpf(false);
+    for wallet in wallets {
+        test.add_account(
+            wallet.pubkey(),
+            Account { lamports: 10 * SOL, ..Account::default() },
+        );
+    }
+    for (address, account) in accounts {
+        test.add_account(address, account);
+    }
+    test.start_with_context().await
+}
+
+// Sends `instructions` paid by the context payer. Program errors come
+// back decoded, the way an app would show them.
+async fn send(
+    ctx: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), Option<AfrodevsError>> {
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(tx)
+        .await
+        .map_err(|e| errors::decode_error_message(&e.to_string()))
+}
+
+async fn account_data(ctx: &mut ProgramTestContext, address: Pubkey) -> Vec<u8> {
+    ctx.banks_client.get_account(address).await.unwrap().expect("account exists").data
+}
+
+async fn initialize(ctx: &mut ProgramTestContext) {
+    let authority = ctx.payer.pubkey();
+    let ix = instructions::initialize(
+        authority,
+        args::Initialize {
+            min_amount: SOL / 10,
+            max_amount: SOL,
+            cooldown_tier_amounts: [SOL / 10, SOL / 4, SOL / 2, SOL],
+            cooldown_tier_seconds: [21_600, 43_200, 86_400, 172_800],
+            daily_global_limit: 50 * SOL,
+            referral_bonus_claimer: SOL / 20,
+            referral_bonus_referrer: SOL / 10,
+        },
+    );
+    send(ctx, &[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn initializes_funds_and_claims_in_process() {
+    let claimer = Keypair::new();
+    let mut ctx = start(&[&claimer], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.authority, authority);
+    assert_eq!(config.cooldown_tier_amounts[1], SOL / 4);
+
+    send(&mut ctx, &[instructions::fund_treasury(authority, 5 * SOL, false)], &[])
+        .await
+        .unwrap();
+    let funder = decode::funder_record(&account_data(&mut ctx, pda::funder_record(&authority).0).await)
+        .unwrap();
+    assert_eq!(funder.total_donated, 5 * SOL);
+
+    let claim = instructions::claim(claimer.pubkey(), SOL / 10, &Default::default());
+    send(&mut ctx, std::slice::from_ref(&claim), &[&claimer]).await.unwrap();
+
+    let record = decode::claimer_record(
+        &account_data(&mut ctx, pda::claimer_record(&claimer.pubkey()).0).await,
+    )
+    .unwrap();
+    assert_eq!(record.total_claims, 1);
+    assert_eq!(record.total_claimed, SOL / 10);
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.total_claims, 1);
+    assert_eq!(config.total_sol_distributed, SOL / 10);
+
+    assert!(matches!(
+        send(&mut ctx, &[claim], &[&claimer]).await,
+        Err(Some(AfrodevsError::CooldownActive))
+    ));
+}
+
+#[tokio::test]
+async fn voucher_signature_only_pays_the_wallet_it_names() {
+    let redeemer = Keypair::new();
+    let front_runner = Keypair::new();
+    let mut ctx = start(&[&redeemer, &front_runner], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    send(&mut ctx, &[instructions::fund_treasury(authority, 5 * SOL, false)], &[])
+        .await
+        .unwrap();
+    let clock: anchor_lang::prelude::Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let create = instructions::create_voucher(authority, "lagos-meetup", SOL / 10, clock.unix_timestamp + 3600, 2);
+    send(&mut ctx, &[create], &[]).await.unwrap();
+
+    // The signature seen in the redeemer's transaction, replayed
+    // with the front-runner's own redeem instruction
+    let [signed_for_redeemer, redeem] = instructions::redeem_voucher(redeemer.pubkey(), "lagos-meetup");
+    let [_, stolen] = instructions::redeem_voucher(front_runner.pubkey(), "lagos-meetup");
+    assert!(matches!(
+        send(&mut ctx, &[signed_for_redeemer.clone(), stolen], &[&front_runner]).await,
+        Err(Some(AfrodevsError::InvalidVoucherCode))
+    ));
+
+    let before = ctx.banks_client.get_balance(redeemer.pubkey()).await.unwrap();
+    send(&mut ctx, &[signed_for_redeemer, redeem], &[&redeemer]).await.unwrap();
+    let voucher_address = pda::voucher(&instructions::voucher_keypair("lagos-meetup").pubkey()).0;
+    let voucher = decode::voucher(&account_data(&mut ctx, voucher_address).await).unwrap();
+    assert_eq!(voucher.uses, 1);
+    assert!(ctx.banks_client.get_balance(redeemer.pubkey()).await.unwrap() > before);
+}
+
+// Account data as a build from before layout versioning wrote it.
+fn program_account(data: Vec<u8>) -> Account {
+    Account { lamports: SOL / 100, data, owner: ID, ..Account::default() }
+}
+
+#[tokio::test]
+async fn migrates_accounts_from_the_first_layout() {
+    let authority = Keypair::new();
+    let wallet = Pubkey::new_unique();
+
+    let legacy_config = FaucetConfigV0 {
+        authority: authority.pubkey(),
+        treasury: pda::treasury_vault().0,
+        is_paused: true,
+        program_version: [1, 0, 0],
+        total_sol_distributed: 3 * SOL,
+        total_claims: 12,
+        total_unique_claimers: 4,
+        min_amount: SOL / 10,
+        max_amount: SOL,
+        cooldown_tier_amounts: [SOL / 10, SOL / 4, SOL / 2, SOL],
+        cooldown_tier_seconds: [21_600, 43_200, 86_400, 172_800],
+        daily_global_limit: 50 * SOL,
+        daily_global_distributed: 0,
+        daily_reset_timestamp: 0,
+        referral_enabled: true,
+        referral_bonus_claimer: SOL / 20,
+        referral_bonus_referrer: SOL / 10,
+        bump: pda::faucet_config().1,
+    };
+    let mut config_data = FaucetConfig::DISCRIMINATOR.to_vec();
+    legacy_config.serialize(&mut config_data).unwrap();
+    assert_eq!(config_data.len(), FaucetConfigV0::LEN);
+
+    // The old layout is a prefix of the current one
+    let mut record = decode::claimer_record(&{
+        let mut data = ClaimerRecord::DISCRIMINATOR.to_vec();
+        data.resize(ClaimerRecord::LEN, 0);
+        data
+    })
+    .unwrap();
+    record.wallet = wallet;
+    record.total_claims = 2;
+    record.last_claim_timestamp = 1_700_000_000;
+    record.pending_referral_bonus = SOL / 10;
+    record.bump = pda::claimer_record(&wallet).1;
+    let mut record_data = Vec::new();
+    record.try_serialize(&mut record_data).unwrap();
+    record_data.resize(ClaimerRecord::V0_LEN, 0);
+
+    let mut ctx = start(
+        &[&authority],
+        vec![
+            (pda::faucet_config().0, program_account(config_data)),
+            (pda::claimer_record(&wallet).0, program_account(record_data)),
+        ],
+    )
+    .await;
+
+    send(&mut ctx, &[instructions::migrate_config(authority.pubkey())], &[&authority])
+        .await
+        .unwrap();
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.layout_version, afrodevsols::constants::CONFIG_LAYOUT_VERSION);
+    assert_eq!(config.paused_features, afrodevsols::constants::PAUSE_ALL);
+    assert_eq!(config.total_claims, 12);
+    assert_eq!(config.cooldown_tier_amounts, legacy_config.cooldown_tier_amounts);
+    assert!(matches!(
+        send(&mut ctx, &[instructions::migrate_config(authority.pubkey())], &[&authority]).await,
+        Err(Some(AfrodevsError::AccountAlreadyMigrated))
+    ));
+
+    let payer = ctx.payer.pubkey();
+    send(&mut ctx, &[instructions::migrate_record(payer, pda::claimer_record(&wallet).0)], &[])
+        .await
+        .unwrap();
+    let migrated = decode::claimer_record(&account_data(&mut ctx, pda::claimer_record(&wallet).0).await)
+        .unwrap();
+    assert_eq!(migrated.pending_referral_bonus, SOL / 10);
+    assert!(migrated.pending_bonus_since > 0);
+    assert_eq!(migrated.current_streak, 1);
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.outstanding_referral_bonuses, SOL / 10);
+}
+
+#[tokio::test]
+async fn queuing_onto_an_expired_bonus_forfeits_it_first() {
+    let referrer = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let mut ctx = start(&[&referrer, &first, &second], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    let setup = [
+        instructions::fund_treasury(authority, 5 * SOL, false),
+        instructions::update_referral_limits(
+            authority,
+            args::UpdateReferralLimits {
+                referral_daily_cap: None,
+                referral_lifetime_cap: None,
+                referral_bonus_expiry_days: Some(1),
+            },
+        ),
+    ];
+    send(&mut ctx, &setup, &[]).await.unwrap();
+
+    let referred = instructions::ClaimOptions {
+        referrer: Some(referrer.pubkey()),
+        ..Default::default()
+    };
+    send(&mut ctx, &[instructions::claim(referrer.pubkey(), SOL / 10, &Default::default())], &[&referrer])
+        .await
+        .unwrap();
+    send(&mut ctx, &[instructions::claim(first.pubkey(), SOL / 10, &referred)], &[&first])
+        .await
+        .unwrap();
+
+    // Two days later the first bonus has expired uncollected
+    let mut clock: anchor_lang::prelude::Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 2 * 86_400;
+    ctx.set_sysvar(&clock);
+
+    send(&mut ctx, &[instructions::claim(second.pubkey(), SOL / 10, &referred)], &[&second])
+        .await
+        .unwrap();
+    let record = decode::claimer_record(
+        &account_data(&mut ctx, pda::claimer_record(&referrer.pubkey()).0).await,
+    )
+    .unwrap();
+    assert_eq!(record.pending_referral_bonus, SOL / 10, "Only the new bonus is pending");
+    assert_eq!(record.pending_bonus_since, clock.unix_timestamp, "Clock restarted");
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.outstanding_referral_bonuses, SOL / 10);
+
+    // The new bonus is fresh, so it can be collected
+    let before = ctx.banks_client.get_balance(referrer.pubkey()).await.unwrap();
+    send(&mut ctx, &[instructions::claim_referral_bonus(referrer.pubkey())], &[&referrer])
+        .await
+        .unwrap();
+    assert_eq!(ctx.banks_client.get_balance(referrer.pubkey()).await.unwrap(), before + SOL / 10);
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.outstanding_referral_bonuses, 0);
+}
+
+fn set_daily_limit(authority: Pubkey, daily_global_limit: u64) -> Instruction {
+    instructions::update_config(
+        authority,
+        args::UpdateConfig {
+            min_amount: None,
+            max_amount: None,
+            cooldown_tier_amounts: None,
+            cooldown_tier_seconds: None,
+            daily_global_limit: Some(daily_global_limit),
+            referral_enabled: None,
+            referral_bonus_claimer: None,
+            referral_bonus_referrer: None,
+            new_authority: None,
+        },
+    )
+}
+
+#[tokio::test]
+async fn streak_bonus_counts_toward_the_daily_limit() {
+    let claimer = Keypair::new();
+    let mut ctx = start(&[&claimer], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    let setup = [
+        instructions::fund_treasury(authority, 5 * SOL, false),
+        instructions::update_streak_config(
+            authority,
+            args::UpdateStreakConfig {
+                streak_bonus_bps: Some(5_000),
+                streak_bonus_max_bps: Some(5_000),
+            },
+        ),
+        set_daily_limit(authority, SOL / 10 + SOL / 50),
+    ];
+    send(&mut ctx, &setup, &[]).await.unwrap();
+
+    let claim = instructions::claim(claimer.pubkey(), SOL / 10, &Default::default());
+    send(&mut ctx, std::slice::from_ref(&claim), &[&claimer]).await.unwrap();
+
+    // Next day: 0.1 SOL fits under the 0.12 SOL limit, but with the
+    // 50% streak bonus the claim pays 0.15 SOL
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 100).unwrap();
+    let mut clock: anchor_lang::prelude::Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += 86_400;
+    ctx.set_sysvar(&clock);
+
+    assert!(matches!(
+        send(&mut ctx, std::slice::from_ref(&claim), &[&claimer]).await,
+        Err(Some(AfrodevsError::DailyLimitReached))
+    ));
+
+    let raise_limit = set_daily_limit(authority, SOL / 10 + SOL / 20);
+    send(&mut ctx, &[raise_limit, claim], &[&claimer]).await.unwrap();
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.daily_global_distributed, SOL / 10 + SOL / 20, "Bonus is counted");
+}
+
+#[tokio::test]
+async fn cpi_claim_needs_the_claimers_board_once_initialized() {
+    let claimer = Keypair::new();
+    let mut ctx = start(&[&claimer], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    let board = instructions::initialize_leaderboard(
+        authority,
+        afrodevsols::constants::LEADERBOARD_KIND_CLAIMERS,
+    );
+    send(&mut ctx, &[board], &[]).await.unwrap();
+
+    let cpi_claim = |with_board| {
+        instructions::cpi_claim(claimer.pubkey(), claimer.pubkey(), claimer.pubkey(), SOL / 10, with_board)
+    };
+    assert!(matches!(
+        send(&mut ctx, &[cpi_claim(false)], &[&claimer]).await,
+        Err(Some(AfrodevsError::LeaderboardRequired))
+    ));
+
+    // With the board, the caller check is next. Sent directly, there is
+    // no integrator program above it.
+    assert!(matches!(
+        send(&mut ctx, &[cpi_claim(true)], &[&claimer]).await,
+        Err(Some(AfrodevsError::UnauthorizedIntegrator))
+    ));
+}
+
+#[tokio::test]
+async fn vouchers_shrink_with_treasury_runway() {
+    let redeemer = Keypair::new();
+    let mut ctx = start(&[&redeemer], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    let clock: anchor_lang::prelude::Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let setup = [
+        instructions::fund_treasury(authority, 5 * SOL, false),
+        // Half payouts while the treasury holds under 100 SOL
+        instructions::update_runway_scaling(
+            authority,
+            args::UpdateRunwayScaling {
+                scaling_mode: Some(afrodevsols::constants::SCALING_MODE_STEPS),
+                scaling_full_balance: None,
+                scaling_floor_balance: None,
+                scaling_floor_bps: None,
+                scaling_step_thresholds: Some([100 * SOL, 0, 0, 0]),
+                scaling_step_bps: Some([5_000, 10_000, 10_000, 10_000]),
+            },
+        ),
+        instructions::create_voucher(authority, "abuja-hackathon", SOL / 10, clock.unix_timestamp + 3600, 1),
+    ];
+    send(&mut ctx, &setup, &[]).await.unwrap();
+
+    let before = ctx.banks_client.get_balance(redeemer.pubkey()).await.unwrap();
+    let redeem = instructions::redeem_voucher(redeemer.pubkey(), "abuja-hackathon");
+    send(&mut ctx, &redeem, &[&redeemer]).await.unwrap();
+
+    let voucher_address = pda::voucher(&instructions::voucher_keypair("abuja-hackathon").pubkey()).0;
+    let voucher = decode::voucher(&account_data(&mut ctx, voucher_address).await).unwrap();
+    assert_eq!(voucher.total_redeemed, SOL / 20);
+    let config = decode::faucet_config(&account_data(&mut ctx, pda::faucet_config().0).await).unwrap();
+    assert_eq!(config.daily_global_distributed, SOL / 20);
+
+    // The redeemer also paid rent for the VoucherRedemption
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let redemption_rent = rent.minimum_balance(afrodevsols::state::VoucherRedemption::LEN);
+    let after = ctx.banks_client.get_balance(redeemer.pubkey()).await.unwrap();
+    assert_eq!(after + redemption_rent, before + SOL / 20);
+}
+
+#[tokio::test]
+async fn eligibility_always_takes_the_wallets_record() {
+    let claimer = Keypair::new();
+    let mut ctx = start(&[&claimer], Vec::new()).await;
+    let authority = ctx.payer.pubkey();
+
+    initialize(&mut ctx).await;
+    send(&mut ctx, &[instructions::fund_treasury(authority, 5 * SOL, false)], &[])
+        .await
+        .unwrap();
+
+    // No record yet: the empty PDA reads as a new wallet.
+    let check = instructions::check_eligibility(claimer.pubkey(), SOL / 10);
+    send(&mut ctx, std::slice::from_ref(&check), &[]).await.unwrap();
+
+    let claim = instructions::claim(claimer.pubkey(), SOL / 10, &Default::default());
+    send(&mut ctx, &[claim], &[&claimer]).await.unwrap();
+    send(&mut ctx, std::slice::from_ref(&check), &[]).await.unwrap();
+
+    // Any other account in the record's slot is refused, so a wallet
+    // with history can't be checked as a new one.
+    let mut spoofed = check;
+    spoofed.accounts[2].pubkey = Pubkey::new_unique();
+    assert!(send(&mut ctx, &[spoofed], &[]).await.is_err());
+}
@@ -0,0 +1,12 @@
+// crates/indexer/src/lib.rs
+
+//! Decodes afrodevsols events from transaction logs into SQLite,
+//! one table per event type. Ingestion is idempotent per signature
+//! and resumes from the last checkpointed slot.
+
+pub mod logs;
+pub mod source;
+pub mod store;
+
+pub use source::{load_fixtures, ProgramTransaction, RpcSource};
+pub use store::Store;
@@ -0,0 +1,41 @@
+// crates/indexer/src/logs.rs
+
+use anchor_lang::prelude::Pubkey;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+const INVOKE_PREFIX: &str = "Program ";
+const DATA_PREFIX: &str = "Program data: ";
+
+// Pulls the raw event payloads (discriminator + borsh body) that
+// `program_id` emitted, in log order. Tracks the invoke stack so
+// events from other programs in the same transaction are skipped.
+pub fn program_events(logs: &[String], program_id: &Pubkey) -> Vec<Vec<u8>> {
+    let program_id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        if let Some(data) = line.strip_prefix(DATA_PREFIX) {
+            if stack.last() == Some(&program_id.as_str()) {
+                if let Ok(bytes) = BASE64.decode(data.trim()) {
+                    events.push(bytes);
+                }
+            }
+            continue;
+        }
+
+        let Some(rest) = line.strip_prefix(INVOKE_PREFIX) else { continue };
+        let mut words = rest.split_whitespace();
+        let (Some(id), Some(action)) = (words.next(), words.next()) else { continue };
+        match action {
+            "invoke" => stack.push(id),
+            "success" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
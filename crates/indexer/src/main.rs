@@ -0,0 +1,65 @@
+// crates/indexer/src/main.rs
+
+use std::path::PathBuf;
+
+use afrodevsols_indexer::{load_fixtures, RpcSource, Store};
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "afrodevsols-indexer", about = "Index afrodevsols events into SQLite")]
+struct Cli {
+    /// SQLite database file
+    #[arg(long, short = 'd', env = "AFRODEVSOLS_INDEX_DB", default_value = "afrodevsols.db")]
+    database: PathBuf,
+
+    #[command(subcommand)]
+    source: Source,
+}
+
+#[derive(Subcommand)]
+enum Source {
+    /// Fetch program transactions since the checkpoint from an RPC node
+    Rpc {
+        #[arg(long, short = 'u', env = "AFRODEVSOLS_RPC_URL", default_value = "https://api.devnet.solana.com")]
+        url: String,
+        /// Start here instead of at the stored checkpoint
+        #[arg(long)]
+        from_slot: Option<u64>,
+    },
+    /// Read transactions from a JSON fixture file
+    Fixtures { path: PathBuf },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut store = Store::open(&cli.database)?;
+
+    let (transactions, events) = match cli.source {
+        Source::Rpc { url, from_slot } => {
+            let rpc = RpcSource::new(url);
+            let from_slot = from_slot.or(store.checkpoint()?).unwrap_or_default();
+            let signatures = rpc.signatures_since(&afrodevsols::ID, from_slot)?;
+
+            let mut events = 0;
+            for signature in &signatures {
+                events += store.ingest(&rpc.transaction(signature)?)?;
+            }
+            (signatures.len(), events)
+        }
+        Source::Fixtures { path } => {
+            let transactions = load_fixtures(&path)?;
+            let mut events = 0;
+            for tx in &transactions {
+                events += store.ingest(tx)?;
+            }
+            (transactions.len(), events)
+        }
+    };
+
+    println!("Processed {transactions} transactions, stored {events} new events");
+    if let Some(slot) = store.checkpoint()? {
+        println!("Checkpoint: slot {slot}");
+    }
+    Ok(())
+}
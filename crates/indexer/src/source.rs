@@ -0,0 +1,126 @@
+// crates/indexer/src/source.rs
+
+use std::fs;
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+// Signatures per getSignaturesForAddress page (the RPC maximum)
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+
+// The parts of a transaction the indexer needs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProgramTransaction {
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub block_time: Option<i64>,
+    #[serde(default)]
+    pub failed: bool,
+    pub logs: Vec<String>,
+}
+
+// Reads a JSON array of ProgramTransaction for offline runs.
+pub fn load_fixtures(path: &Path) -> Result<Vec<ProgramTransaction>> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let mut transactions: Vec<ProgramTransaction> = serde_json::from_str(&text)?;
+    transactions.sort_by_key(|tx| tx.slot);
+    Ok(transactions)
+}
+
+pub struct RpcSource {
+    url: String,
+}
+
+impl RpcSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = ureq::post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method} returned no result"))
+    }
+
+    // Signatures touching `program_id` at or after `from_slot`, oldest
+    // first. The checkpoint slot itself is re-read because a run may
+    // have stopped partway through it; already-seen signatures are
+    // skipped by the store.
+    pub fn signatures_since(&self, program_id: &Pubkey, from_slot: u64) -> Result<Vec<String>> {
+        let mut signatures = Vec::new();
+        let mut before: Option<String> = None;
+
+        'pages: loop {
+            let mut options = json!({ "limit": SIGNATURE_PAGE_SIZE, "commitment": "confirmed" });
+            if let Some(before) = &before {
+                options["before"] = json!(before);
+            }
+            let page = self.call("getSignaturesForAddress", json!([program_id.to_string(), options]))?;
+            let page = page.as_array().ok_or_else(|| anyhow!("expected a signature list"))?;
+
+            for entry in page {
+                let slot = entry["slot"].as_u64().unwrap_or_default();
+                if slot < from_slot {
+                    break 'pages;
+                }
+                let signature = entry["signature"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("signature entry without a signature"))?;
+                signatures.push(signature.to_string());
+            }
+
+            if page.len() < SIGNATURE_PAGE_SIZE {
+                break;
+            }
+            before = signatures.last().cloned();
+        }
+
+        signatures.reverse();
+        Ok(signatures)
+    }
+
+    pub fn transaction(&self, signature: &str) -> Result<ProgramTransaction> {
+        let result = self.call(
+            "getTransaction",
+            json!([
+                signature,
+                { "encoding": "json", "commitment": "confirmed", "maxSupportedTransactionVersion": 0 }
+            ]),
+        )?;
+        if result.is_null() {
+            bail!("transaction {signature} not found");
+        }
+
+        let meta = &result["meta"];
+        let logs = meta["logMessages"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(|l| l.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+
+        Ok(ProgramTransaction {
+            signature: signature.to_string(),
+            slot: result["slot"].as_u64().unwrap_or_default(),
+            block_time: result["blockTime"].as_i64(),
+            failed: !meta["err"].is_null(),
+            logs,
+        })
+    }
+}
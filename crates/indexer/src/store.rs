@@ -0,0 +1,411 @@
+// crates/indexer/src/store.rs
+
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::{bail, Result};
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Transaction};
+
+use crate::logs::program_events;
+use crate::source::ProgramTransaction;
+
+// How an event field is stored. SQLite is loosely typed, so a u64
+// above i64::MAX falls back to TEXT rather than wrapping.
+pub trait Column {
+    const SQL_TYPE: &'static str;
+    fn value(&self) -> Value;
+}
+
+impl Column for Pubkey {
+    const SQL_TYPE: &'static str = "TEXT";
+    fn value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl Column for String {
+    const SQL_TYPE: &'static str = "TEXT";
+    fn value(&self) -> Value {
+        Value::Text(self.clone())
+    }
+}
+
+impl Column for u64 {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        i64::try_from(*self).map_or_else(|_| Value::Text(self.to_string()), Value::Integer)
+    }
+}
+
+impl Column for i64 {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        Value::Integer(*self)
+    }
+}
+
+impl Column for u32 {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        Value::Integer(*self as i64)
+    }
+}
+
+impl Column for u16 {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        Value::Integer(*self as i64)
+    }
+}
+
+impl Column for u8 {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        Value::Integer(*self as i64)
+    }
+}
+
+impl Column for bool {
+    const SQL_TYPE: &'static str = "INTEGER";
+    fn value(&self) -> Value {
+        Value::Integer(*self as i64)
+    }
+}
+
+impl Column for [u8; 32] {
+    const SQL_TYPE: &'static str = "BLOB";
+    fn value(&self) -> Value {
+        Value::Blob(self.to_vec())
+    }
+}
+
+// Lists are stored as JSON arrays
+impl Column for Vec<Pubkey> {
+    const SQL_TYPE: &'static str = "TEXT";
+    fn value(&self) -> Value {
+        let keys: Vec<String> = self.iter().map(Pubkey::to_string).collect();
+        Value::Text(serde_json::to_string(&keys).unwrap_or_default())
+    }
+}
+
+impl Column for Vec<u64> {
+    const SQL_TYPE: &'static str = "TEXT";
+    fn value(&self) -> Value {
+        Value::Text(serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+// Lets the table macro read a field's column type from its name.
+fn sql_type<E, T: Column>(_field: fn(&E) -> &T) -> &'static str {
+    T::SQL_TYPE
+}
+
+// Bump when an event gains fields so older databases get the new
+// columns. Stored in SQLite's `user_version` pragma.
+pub const SCHEMA_VERSION: i64 = 1;
+
+// One table per event. Every table is keyed by (signature, event_index)
+// so replaying a transaction never duplicates rows. Field names are
+// quoted since some (like `limit`) are SQL keywords.
+macro_rules! event_tables {
+    ($($event:ident => $table:literal { $($field:ident),* $(,)? }),* $(,)?) => {
+        pub const EVENT_TABLES: &[&str] = &[$($table),*];
+
+        // Creates missing tables. With `add_columns`, also adds any
+        // event field an existing table was created without.
+        fn create_event_tables(conn: &Connection, add_columns: bool) -> Result<()> {
+            $(
+                let fields: &[(&str, &str)] = &[
+                    $((stringify!($field), sql_type(|e: &afrodevsols::$event| &e.$field))),*
+                ];
+                let columns: Vec<String> = fields
+                    .iter()
+                    .map(|(name, sql_type)| format!("\"{name}\" {sql_type}"))
+                    .collect();
+                conn.execute_batch(&format!(
+                    "CREATE TABLE IF NOT EXISTS {} (
+                        signature TEXT NOT NULL,
+                        event_index INTEGER NOT NULL,
+                        slot INTEGER NOT NULL,
+                        block_time INTEGER,
+                        {},
+                        PRIMARY KEY (signature, event_index)
+                    );",
+                    $table,
+                    columns.join(",\n"),
+                ))?;
+                if add_columns {
+                    add_missing_columns(conn, $table, fields)?;
+                }
+            )*
+            Ok(())
+        }
+
+        // Returns the table written to, or None for an unknown event.
+        fn insert_event(
+            db: &Transaction,
+            tx: &ProgramTransaction,
+            event_index: usize,
+            data: &[u8],
+        ) -> Result<Option<&'static str>> {
+            if data.len() < 8 {
+                return Ok(None);
+            }
+            let (discriminator, mut body) = data.split_at(8);
+            $(
+                if discriminator == afrodevsols::$event::DISCRIMINATOR {
+                    // A layout this build doesn't know (or a corrupt log)
+                    // shouldn't stall ingestion of everything after it
+                    let event = match afrodevsols::$event::deserialize(&mut body) {
+                        Ok(event) => event,
+                        Err(err) => {
+                            eprintln!(
+                                "Skipping undecodable {} at {}#{event_index}: {err}",
+                                stringify!($event),
+                                tx.signature,
+                            );
+                            return Ok(None);
+                        }
+                    };
+                    let fields: &[&str] = &[$(concat!("\"", stringify!($field), "\"")),*];
+                    let mut values = vec![
+                        Value::Text(tx.signature.clone()),
+                        Value::Integer(event_index as i64),
+                        tx.slot.value(),
+                        tx.block_time.map_or(Value::Null, Value::Integer),
+                    ];
+                    $(values.push(event.$field.value());)*
+
+                    let placeholders = vec!["?"; values.len()].join(", ");
+                    db.execute(
+                        &format!(
+                            "INSERT OR IGNORE INTO {} (signature, event_index, slot, block_time, {}) VALUES ({})",
+                            $table,
+                            fields.join(", "),
+                            placeholders,
+                        ),
+                        params_from_iter(values),
+                    )?;
+                    return Ok(Some($table));
+                }
+            )*
+            Ok(None)
+        }
+    };
+}
+
+event_tables! {
+    ClaimEvent => "claim_events" {
+        claimer, amount, timestamp, claimer_total, claimer_claim_count, cooldown_ends_at,
+        tier_index, was_referral, referral_bonus_applied, current_streak, longest_streak,
+        streak_bonus_applied, base_amount, scaled_amount, runway_multiplier_bps,
+    },
+    SponsoredClaimEvent => "sponsored_claim_events" { claimer, relayer, reimbursement, timestamp },
+    CpiClaimEvent => "cpi_claim_events" { integrator, claimer, destination, amount, timestamp },
+    CampaignCreatedEvent => "campaign_created_events" {
+        campaign, campaign_id, name, budget, starts_at, ends_at, authority, timestamp,
+    },
+    CampaignClaimEvent => "campaign_claim_events" {
+        campaign, claimer, amount, wallet_total, remaining_budget, timestamp,
+    },
+    CampaignClosedEvent => "campaign_closed_events" {
+        campaign, total_claimed, remaining_budget, authority, timestamp,
+    },
+    CampaignSweptEvent => "campaign_swept_events" {
+        campaign, amount, cranked_by, timestamp, new_treasury_balance,
+    },
+    CampaignAccountClosedEvent => "campaign_account_closed_events" {
+        campaign, total_claimed, swept_amount, authority, timestamp,
+    },
+    VoucherCreatedEvent => "voucher_created_events" {
+        voucher, amount, expires_at, max_uses, authority, timestamp,
+    },
+    VoucherRedeemedEvent => "voucher_redeemed_events" { voucher, wallet, amount, uses, timestamp },
+    VoucherClosedEvent => "voucher_closed_events" {
+        voucher, uses, total_redeemed, authority, timestamp,
+    },
+    BountyCreatedEvent => "bounty_created_events" {
+        bounty, bounty_id, reward, deadline, description_hash, authority, timestamp,
+    },
+    BountySubmittedEvent => "bounty_submitted_events" { bounty, submitter, work_uri_hash, timestamp },
+    BountyAwardedEvent => "bounty_awarded_events" {
+        bounty, winner, reward, work_uri_hash, authority, timestamp,
+    },
+    BountyExpiredEvent => "bounty_expired_events" {
+        bounty, reward, cranked_by, timestamp, new_treasury_balance,
+    },
+    BountyClosedEvent => "bounty_closed_events" {
+        bounty, bounty_id, status, authority, timestamp,
+    },
+    ConfigMigratedEvent => "config_migrated_events" { authority, from_version, to_version, timestamp },
+    RecordMigratedEvent => "record_migrated_events" { record, old_len, new_len, payer, timestamp },
+    ReferralPendingEvent => "referral_pending_events" {
+        referrer, referred, timestamp, claims_required, unlocks_after,
+    },
+    ReferralConfirmedEvent => "referral_confirmed_events" {
+        referrer, referred, timestamp, bonus_queued_for_referrer, bonus_applied_to_referred,
+    },
+    AncestorReferralBonusEvent => "ancestor_referral_bonus_events" {
+        ancestor, referred, level, amount, timestamp,
+    },
+    ReferralBonusClaimedEvent => "referral_bonus_claimed_events" { referrer, amount, timestamp },
+    ReferralCodeRegisteredEvent => "referral_code_registered_events" { code, owner, timestamp },
+    ReferralCodeTransferredEvent => "referral_code_transferred_events" {
+        code, previous_owner, new_owner, timestamp,
+    },
+    ReferralCodeReleasedEvent => "referral_code_released_events" { code, owner, timestamp },
+    ReferralBonusExpiredEvent => "referral_bonus_expired_events" {
+        referrer, amount, cranked_by, timestamp,
+    },
+    SpecialGrantEvent => "special_grant_events" {
+        recipient, amount, reason, is_public, authority, timestamp, new_treasury_balance,
+    },
+    GrantEscrowedEvent => "grant_escrowed_events" {
+        grant, recipient, amount, reason, is_public, authority, expires_at, timestamp,
+    },
+    GrantAcceptedEvent => "grant_accepted_events" { grant, recipient, amount, timestamp },
+    GrantExpiredEvent => "grant_expired_events" {
+        grant, recipient, amount, cranked_by, timestamp, new_treasury_balance,
+    },
+    MilestoneGrantCreatedEvent => "milestone_grant_created_events" {
+        grant, recipient, total_amount, milestone_count, reason, is_public, authority, timestamp,
+    },
+    MilestoneReleasedEvent => "milestone_released_events" {
+        grant, recipient, milestone_index, amount, description_hash, released_total, timestamp,
+    },
+    MilestoneCancelledEvent => "milestone_cancelled_events" {
+        grant, recipient, milestone_index, amount, authority, timestamp,
+    },
+    GrantRecordClosedEvent => "grant_record_closed_events" {
+        grant, recipient, status, authority, timestamp,
+    },
+    BulkGrantEvent => "bulk_grant_events" {
+        recipients, amounts, reason, is_public, batch_id, total_amount, authority, timestamp,
+    },
+    ConfigUpdatedEvent => "config_updated_events" { authority, timestamp, field_changed },
+    TreasuryFundedEvent => "treasury_funded_events" {
+        funder, amount, new_balance, timestamp, funder_total_donated, funder_donation_count,
+    },
+    FunderNameUpdatedEvent => "funder_name_updated_events" { funder, display_name, timestamp },
+    WithdrawalEvent => "withdrawal_events" {
+        authority, amount, destination, new_balance, timestamp,
+    },
+    TreasuryLowEvent => "treasury_low_events" {
+        balance, low_water_mark, auto_paused, pause_reason, timestamp,
+    },
+    PauseUpdatedEvent => "pause_updated_events" {
+        features, paused, reason, paused_features, authority, timestamp,
+    },
+    CircuitBreakerTrippedEvent => "circuit_breaker_tripped_events" {
+        window_outflow, limit, window_minutes, timestamp,
+    },
+    TreasuryRecoveredEvent => "treasury_recovered_events" {
+        balance, high_water_mark, funder, timestamp,
+    },
+    WalletBlockedEvent => "wallet_blocked_events" { target_wallet, is_blocked, authority, timestamp },
+}
+
+fn add_missing_columns(conn: &Connection, table: &str, fields: &[(&str, &str)]) -> Result<()> {
+    let existing: Vec<String> = conn
+        .prepare(&format!("PRAGMA table_info({table})"))?
+        .query_map([], |row| row.get(1))?
+        .collect::<rusqlite::Result<_>>()?;
+    for (name, sql_type) in fields {
+        if !existing.iter().any(|column| column == name) {
+            conn.execute_batch(&format!("ALTER TABLE {table} ADD COLUMN \"{name}\" {sql_type}"))?;
+        }
+    }
+    Ok(())
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS processed_transactions (
+                signature TEXT PRIMARY KEY,
+                slot INTEGER NOT NULL,
+                event_count INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS checkpoint (
+                id INTEGER PRIMARY KEY CHECK (id = 0),
+                slot INTEGER NOT NULL
+            );",
+        )?;
+
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        if version > SCHEMA_VERSION {
+            bail!("Database schema version {version} is newer than this indexer ({SCHEMA_VERSION})");
+        }
+        create_event_tables(&conn, version < SCHEMA_VERSION)?;
+        conn.execute_batch(&format!("PRAGMA user_version = {SCHEMA_VERSION}"))?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    // Highest slot fully ingested, if any.
+    pub fn checkpoint(&self) -> Result<Option<u64>> {
+        let slot: Option<i64> = self
+            .conn
+            .query_row("SELECT slot FROM checkpoint WHERE id = 0", [], |row| row.get(0))
+            .optional()?;
+        Ok(slot.map(|slot| slot as u64))
+    }
+
+    // Stores every event in `tx` and advances the checkpoint, all in
+    // one database transaction. Returns the number of new events;
+    // a transaction seen before is skipped and counts as 0.
+    pub fn ingest(&mut self, tx: &ProgramTransaction) -> Result<usize> {
+        let db = self.conn.transaction()?;
+
+        let seen: bool = db
+            .query_row(
+                "SELECT 1 FROM processed_transactions WHERE signature = ?1",
+                [&tx.signature],
+                |_| Ok(true),
+            )
+            .optional()?
+            .unwrap_or(false);
+        if seen {
+            return Ok(0);
+        }
+
+        let mut stored = 0;
+        if !tx.failed {
+            for (index, data) in program_events(&tx.logs, &afrodevsols::ID).iter().enumerate() {
+                if insert_event(&db, tx, index, data)?.is_some() {
+                    stored += 1;
+                }
+            }
+        }
+
+        db.execute(
+            "INSERT INTO processed_transactions (signature, slot, event_count) VALUES (?1, ?2, ?3)",
+            params![tx.signature, tx.slot as i64, stored as i64],
+        )?;
+        db.execute(
+            "INSERT INTO checkpoint (id, slot) VALUES (0, ?1)
+             ON CONFLICT (id) DO UPDATE SET slot = MAX(slot, excluded.slot)",
+            [tx.slot as i64],
+        )?;
+        db.commit()?;
+
+        Ok(stored)
+    }
+}
@@ -0,0 +1,211 @@
+// crates/indexer/tests/indexer.rs
+
+use afrodevsols::{ClaimEvent, WithdrawalEvent};
+use afrodevsols_indexer::store::SCHEMA_VERSION;
+use afrodevsols_indexer::{load_fixtures, ProgramTransaction, Store};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Event;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+fn claim_event(claimer: Pubkey, amount: u64) -> ClaimEvent {
+    ClaimEvent {
+        claimer,
+        amount,
+        timestamp: 1_700_000_000,
+        claimer_total: amount,
+        claimer_claim_count: 1,
+        cooldown_ends_at: 1_700_021_600,
+        tier_index: 0,
+        was_referral: false,
+        referral_bonus_applied: 0,
+        current_streak: 1,
+        longest_streak: 1,
+        streak_bonus_applied: 0,
+        base_amount: amount,
+        scaled_amount: amount,
+        runway_multiplier_bps: 10_000,
+    }
+}
+
+// Logs shaped like a real invocation, with a CPI to another program
+// that also writes a "Program data" line.
+fn transaction(signature: &str, slot: u64, events: &[Vec<u8>]) -> ProgramTransaction {
+    let program = afrodevsols::ID.to_string();
+    let other = Pubkey::new_unique().to_string();
+
+    let mut logs = vec![
+        format!("Program {program} invoke [1]"),
+        String::from("Program log: Instruction: Claim"),
+        format!("Program {other} invoke [2]"),
+        format!("Program data: {}", BASE64.encode(&events[0])),
+        format!("Program {other} success"),
+    ];
+    logs.extend(events.iter().map(|data| format!("Program data: {}", BASE64.encode(data))));
+    logs.push(format!("Program {program} consumed 20000 of 200000 compute units"));
+    logs.push(format!("Program {program} success"));
+
+    ProgramTransaction {
+        signature: signature.to_string(),
+        slot,
+        block_time: Some(1_700_000_000),
+        failed: false,
+        logs,
+    }
+}
+
+fn count(store: &Store, table: &str) -> i64 {
+    store
+        .connection()
+        .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn stores_program_events_once() {
+    let mut store = Store::open_in_memory().unwrap();
+    let claimer = Pubkey::new_unique();
+
+    let tx = transaction(
+        "sig-1",
+        100,
+        &[
+            claim_event(claimer, 100_000_000).data(),
+            WithdrawalEvent {
+                authority: Pubkey::new_unique(),
+                amount: 5,
+                destination: Pubkey::new_unique(),
+                new_balance: 10,
+                timestamp: 1_700_000_000,
+            }
+            .data(),
+        ],
+    );
+
+    assert_eq!(store.ingest(&tx).unwrap(), 2, "CPI event is ignored");
+    assert_eq!(store.ingest(&tx).unwrap(), 0, "Replays are skipped");
+    assert_eq!(count(&store, "claim_events"), 1);
+    assert_eq!(count(&store, "withdrawal_events"), 1);
+
+    let (stored_claimer, amount): (String, i64) = store
+        .connection()
+        .query_row("SELECT claimer, amount FROM claim_events", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(stored_claimer, claimer.to_string());
+    assert_eq!(amount, 100_000_000);
+}
+
+#[test]
+fn skips_failed_transactions_and_tracks_checkpoint() {
+    let mut store = Store::open_in_memory().unwrap();
+    assert_eq!(store.checkpoint().unwrap(), None);
+
+    let mut failed = transaction("sig-2", 250, &[claim_event(Pubkey::new_unique(), 1).data()]);
+    failed.failed = true;
+    assert_eq!(store.ingest(&failed).unwrap(), 0);
+
+    let older = transaction("sig-3", 200, &[claim_event(Pubkey::new_unique(), 1).data()]);
+    assert_eq!(store.ingest(&older).unwrap(), 1);
+
+    assert_eq!(count(&store, "claim_events"), 1);
+    assert_eq!(store.checkpoint().unwrap(), Some(250), "Checkpoint never moves back");
+}
+
+#[test]
+fn resumes_from_a_database_file() {
+    let dir = std::env::temp_dir().join(format!("afrodevsols-indexer-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let db_path = dir.join("index.db");
+    let fixture_path = dir.join("transactions.json");
+    let _ = std::fs::remove_file(&db_path);
+
+    let tx = transaction("sig-4", 300, &[claim_event(Pubkey::new_unique(), 7).data()]);
+    let fixture = serde_json::json!([{
+        "signature": tx.signature,
+        "slot": tx.slot,
+        "block_time": tx.block_time,
+        "logs": tx.logs,
+    }]);
+    std::fs::write(&fixture_path, fixture.to_string()).unwrap();
+
+    let transactions = load_fixtures(&fixture_path).unwrap();
+    {
+        let mut store = Store::open(&db_path).unwrap();
+        assert_eq!(store.ingest(&transactions[0]).unwrap(), 1);
+    }
+
+    let mut store = Store::open(&db_path).unwrap();
+    assert_eq!(store.checkpoint().unwrap(), Some(300));
+    assert_eq!(store.ingest(&transactions[0]).unwrap(), 0);
+    assert_eq!(count(&store, "claim_events"), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn skips_undecodable_events() {
+    let mut store = Store::open_in_memory().unwrap();
+
+    // Right discriminator, truncated body
+    let mut truncated = claim_event(Pubkey::new_unique(), 1).data();
+    truncated.truncate(20);
+    let tx = transaction("sig-5", 400, &[truncated, claim_event(Pubkey::new_unique(), 2).data()]);
+
+    assert_eq!(store.ingest(&tx).unwrap(), 1);
+    assert_eq!(count(&store, "claim_events"), 1);
+    assert_eq!(store.checkpoint().unwrap(), Some(400));
+}
+
+#[test]
+fn adds_columns_missing_from_an_older_schema() {
+    let dir = std::env::temp_dir().join(format!("afrodevsols-indexer-schema-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let db_path = dir.join("index.db");
+    let _ = std::fs::remove_file(&db_path);
+
+    // claim_events as an unversioned database created it, before
+    // the event gained its streak and scaling fields
+    {
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE claim_events (
+                signature TEXT NOT NULL,
+                event_index INTEGER NOT NULL,
+                slot INTEGER NOT NULL,
+                block_time INTEGER,
+                \"claimer\" TEXT,
+                \"amount\" INTEGER,
+                \"timestamp\" INTEGER,
+                PRIMARY KEY (signature, event_index)
+            );",
+        )
+        .unwrap();
+    }
+
+    let mut store = Store::open(&db_path).unwrap();
+    let version: i64 = store
+        .connection()
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(version, SCHEMA_VERSION);
+
+    let tx = transaction("sig-6", 500, &[claim_event(Pubkey::new_unique(), 3).data()]);
+    assert_eq!(store.ingest(&tx).unwrap(), 1);
+    let multiplier: i64 = store
+        .connection()
+        .query_row("SELECT runway_multiplier_bps FROM claim_events", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(multiplier, 10_000);
+
+    // A database written by a newer indexer is refused
+    drop(store);
+    rusqlite::Connection::open(&db_path)
+        .unwrap()
+        .execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION + 1))
+        .unwrap();
+    assert!(Store::open(&db_path).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -6,6 +6,16 @@ pub const CLAIMER_SEED: &[u8] = b"claimer";
 pub const REFERRAL_SEED: &[u8] = b"referral";
 pub const GRANT_RECORD_SEED: &[u8] = b"grant_record";
 pub const DAILY_STATS_SEED: &[u8] = b"daily_stats";
+pub const GRANT_MILESTONES_SEED: &[u8] = b"grant_milestones";
+pub const REFERRAL_CODE_SEED: &[u8] = b"referral_code";
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+pub const FUNDER_SEED: &[u8] = b"funder";
+pub const CAMPAIGN_SEED: &[u8] = b"campaign";
+pub const CAMPAIGN_CLAIM_SEED: &[u8] = b"campaign_claim";
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+pub const VOUCHER_REDEMPTION_SEED: &[u8] = b"voucher_redemption";
+pub const BOUNTY_SEED: &[u8] = b"bounty";
+pub const BOUNTY_SUBMISSION_SEED: &[u8] = b"bounty_submission";
 
 // Minimum SOL to always keep in treasury for rent reserves
 // 0.01 SOL in lamports
@@ -17,12 +27,102 @@ pub const MAX_BULK_RECIPIENTS: usize = 20;
 // Maximum length of a reason string
 pub const MAX_REASON_LENGTH: usize = 64;
 
-// Maximum length of a display name (stored off-chain but validated here)
+// Maximum length of a funder display name or referral code
 pub const MAX_NAME_LENGTH: usize = 30;
 
+// Minimum length of a referral code
+pub const MIN_REFERRAL_CODE_LENGTH: usize = 3;
+
+// Escrowed grant expiry bounds
+pub const MIN_GRANT_EXPIRY_SECONDS: i64 = SECONDS_PER_HOUR;
+pub const MAX_GRANT_EXPIRY_SECONDS: i64 = 90 * SECONDS_PER_DAY;
+
+// GrantRecord.status values
+pub const GRANT_STATUS_PAID: u8 = 0;      // Pushed straight to the recipient
+pub const GRANT_STATUS_PENDING: u8 = 1;   // Escrowed, waiting for accept_grant
+pub const GRANT_STATUS_ACCEPTED: u8 = 2;  // Pulled by the recipient
+pub const GRANT_STATUS_EXPIRED: u8 = 3;   // Returned to the treasury after expiry
+pub const GRANT_STATUS_IN_PROGRESS: u8 = 4; // Milestones still outstanding
+pub const GRANT_STATUS_COMPLETED: u8 = 5; // Every milestone released
+pub const GRANT_STATUS_CANCELLED: u8 = 6; // Remaining milestones returned to the treasury
+
+// GrantRecord.grant_type values
+pub const GRANT_TYPE_SPECIAL: u8 = 0;
+pub const GRANT_TYPE_BULK: u8 = 1;
+pub const GRANT_TYPE_MILESTONE: u8 = 2;
+
+// Campaign.status values
+pub const CAMPAIGN_STATUS_ACTIVE: u8 = 0;   // Claimable between starts_at and ends_at
+pub const CAMPAIGN_STATUS_CLOSED: u8 = 1;   // Ended early by the authority
+pub const CAMPAIGN_STATUS_SWEPT: u8 = 2;    // Unspent budget returned to the treasury
+
+// Bounty.status values
+pub const BOUNTY_STATUS_OPEN: u8 = 0;
+pub const BOUNTY_STATUS_AWARDED: u8 = 1;
+pub const BOUNTY_STATUS_EXPIRED: u8 = 2;     // Reward returned to the treasury
+
+// Time after a bounty's deadline for the authority to judge
+// submissions before the reward can be returned to the treasury
+pub const BOUNTY_REVIEW_SECONDS: i64 = 7 * SECONDS_PER_DAY;
+
+// Maximum milestones attached to a single grant
+pub const MAX_MILESTONES: usize = 8;
+
+// Basis points denominator (10_000 = 100%)
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+// Maximum ancestors above the direct referrer that can earn a share
+pub const MAX_REFERRAL_LEVELS: usize = 5;
+
+// Leaderboard size and kinds (kind byte is part of the PDA seed)
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const LEADERBOARD_KIND_CLAIMERS: u8 = 0;   // By ClaimerRecord.total_claimed
+pub const LEADERBOARD_KIND_REFERRERS: u8 = 1;  // By ClaimerRecord.referral_count
+pub const LEADERBOARD_KIND_DONORS: u8 = 2;     // By lamports sent through fund_treasury
+
+// FaucetConfig.paused_features bits. Bit i's reason is pause_reasons[i].
+pub const PAUSE_CLAIMS: u16 = 1 << 0;                  // Every claim entry point
+pub const PAUSE_TIER_0: u16 = 1 << 1;                  // Tier i is PAUSE_TIER_0 << i
+pub const PAUSE_REFERRAL_CONFIRMATION: u16 = 1 << 5;   // Pending referrals stay pending
+pub const PAUSE_REFERRAL_PAYOUT: u16 = 1 << 6;         // claim_referral_bonus
+pub const PAUSE_GRANTS: u16 = 1 << 7;                  // Sending or releasing grants from the treasury
+pub const PAUSE_WITHDRAWALS: u16 = 1 << 8;             // withdraw_treasury
+pub const PAUSE_FEATURE_COUNT: usize = 9;
+pub const PAUSE_ALL: u16 = (1 << PAUSE_FEATURE_COUNT) - 1;
+
+// What a low-treasury auto-pause switches off
+pub const PAUSE_ON_LOW_TREASURY: u16 = PAUSE_CLAIMS | PAUSE_REFERRAL_PAYOUT;
+
+// FaucetConfig.pause_reasons values. Codes above LOW_TREASURY are
+// free for operators to assign their own meaning.
+pub const PAUSE_REASON_NONE: u8 = 0;
+pub const PAUSE_REASON_MANUAL: u8 = 1;
+pub const PAUSE_REASON_LOW_TREASURY: u8 = 2;
+pub const PAUSE_REASON_CIRCUIT_BREAKER: u8 = 3;
+
+// Circuit breaker: one outflow bucket per minute, and the longest
+// window the limit can be measured over
+pub const OUTFLOW_BUCKET_COUNT: usize = 15;
+
+// FaucetConfig.scaling_mode values
+pub const SCALING_MODE_OFF: u8 = 0;
+pub const SCALING_MODE_LINEAR: u8 = 1;
+pub const SCALING_MODE_STEPS: u8 = 2;
+
+// Number of configurable runway scaling steps
+pub const SCALING_STEP_COUNT: usize = 4;
+
+// Integrator programs allowed to claim through CPI
+pub const MAX_INTEGRATORS: usize = 8;
+
+// FaucetConfig.layout_version written by this build. The layout
+// deployed before the field existed counts as 0.
+pub const CONFIG_LAYOUT_VERSION: u8 = 1;
+
 // Cooldown tier count
 pub const COOLDOWN_TIER_COUNT: usize = 4;
 
 // Seconds in common time units
+pub const SECONDS_PER_MINUTE: i64 = 60;
 pub const SECONDS_PER_HOUR: i64 = 3600;
 pub const SECONDS_PER_DAY: i64 = 86400;
\ No newline at end of file
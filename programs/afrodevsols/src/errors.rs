@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum AfrodevsError {
-    #[msg("The faucet is currently paused. Check back soon!")]
+    #[msg("Claims are currently paused. Check back soon!")]
     FaucetPaused,
 
     #[msg("Treasury balance is too low. Faucet needs refilling.")]
@@ -66,4 +66,175 @@ pub enum AfrodevsError {
 
     #[msg("Arithmetic overflow occurred.")]
     Overflow,
+
+    #[msg("Grant expiry must be between 1 hour and 90 days.")]
+    InvalidGrantExpiry,
+
+    #[msg("This grant is not waiting to be accepted.")]
+    GrantNotPending,
+
+    #[msg("This grant has expired and can no longer be accepted.")]
+    GrantExpired,
+
+    #[msg("This grant has not expired yet.")]
+    GrantNotExpired,
+
+    #[msg("Provide 1 to 8 milestones, each with an amount and a description hash.")]
+    InvalidMilestones,
+
+    #[msg("That milestone index does not exist on this grant.")]
+    InvalidMilestoneIndex,
+
+    #[msg("This milestone has already been released.")]
+    MilestoneAlreadyReleased,
+
+    #[msg("This grant has no outstanding milestones.")]
+    GrantNotInProgress,
+
+    #[msg("Referral codes must be 3-30 characters of a-z, 0-9, '-' or '_'.")]
+    InvalidReferralCode,
+
+    #[msg("The referral and referrer records are required when claiming with or after a referral.")]
+    ReferralRecordRequired,
+
+    #[msg("Basis point values cannot exceed 10,000.")]
+    InvalidBasisPoints,
+
+    #[msg("Too many referral levels. Maximum is 5 ancestors.")]
+    TooManyReferralLevels,
+
+    #[msg("The referral chain loops back on itself.")]
+    ReferralCycle,
+
+    #[msg("This referrer has reached today's referral limit.")]
+    ReferralDailyCapReached,
+
+    #[msg("This referrer has reached the lifetime referral limit.")]
+    ReferralLifetimeCapReached,
+
+    #[msg("This referral bonus has expired and can no longer be collected.")]
+    ReferralBonusExpired,
+
+    #[msg("This referral bonus has not expired yet.")]
+    ReferralBonusNotExpired,
+
+    #[msg("This withdrawal would spend SOL reserved for outstanding referral bonuses.")]
+    LiabilityReserveViolation,
+
+    #[msg("Unknown leaderboard kind.")]
+    InvalidLeaderboardKind,
+
+    #[msg("Display name is too long. Maximum is 30 bytes.")]
+    NameTooLong,
+
+    #[msg("The high-water mark must be at or above the low-water mark.")]
+    InvalidWaterMarks,
+
+    #[msg("Invalid runway scaling settings.")]
+    InvalidScalingConfig,
+
+    #[msg("Sponsored claims are currently disabled.")]
+    SponsoredClaimsDisabled,
+
+    #[msg("Sponsored claims need an existing ClaimerRecord. Call sponsor_claimer_record first.")]
+    SponsoredRecordMissing,
+
+    #[msg("cpi_claim must be invoked directly by an allowlisted integrator program.")]
+    UnauthorizedIntegrator,
+
+    #[msg("The integrator allowlist is full.")]
+    IntegratorListFull,
+
+    #[msg("Integrator program id cannot be the default pubkey.")]
+    InvalidIntegrator,
+
+    #[msg("Claims for this tier are currently paused.")]
+    TierPaused,
+
+    #[msg("Referral bonus payouts are currently paused.")]
+    ReferralPayoutPaused,
+
+    #[msg("Grants are currently paused.")]
+    GrantsPaused,
+
+    #[msg("Treasury withdrawals are currently paused.")]
+    WithdrawalsPaused,
+
+    #[msg("Unknown or empty pause feature mask.")]
+    InvalidPauseFeatures,
+
+    #[msg("Circuit breaker window must be between 1 and 15 minutes.")]
+    InvalidCircuitBreakerWindow,
+
+    #[msg("Invalid campaign schedule or tiers.")]
+    InvalidCampaign,
+
+    #[msg("This campaign is not open for claims.")]
+    CampaignNotOpen,
+
+    #[msg("Wallet is not on this campaign's allowlist.")]
+    NotOnCampaignAllowlist,
+
+    #[msg("You have reached this campaign's per-wallet limit.")]
+    CampaignWalletLimitReached,
+
+    #[msg("This campaign's budget is used up.")]
+    CampaignBudgetExhausted,
+
+    #[msg("Campaign is still running or was already swept.")]
+    CampaignNotFinished,
+
+    #[msg("Vouchers need at least one use and an expiry in the future.")]
+    InvalidVoucher,
+
+    #[msg("That voucher code is not valid.")]
+    InvalidVoucherCode,
+
+    #[msg("This voucher has expired.")]
+    VoucherExpired,
+
+    #[msg("This voucher has no uses left.")]
+    VoucherUsedUp,
+
+    #[msg("You have already redeemed this voucher.")]
+    VoucherAlreadyRedeemed,
+
+    #[msg("Voucher can only be closed once expired or used up.")]
+    VoucherStillActive,
+
+    #[msg("Bounty deadline must be in the future.")]
+    InvalidBountyDeadline,
+
+    #[msg("This bounty is not open.")]
+    BountyNotOpen,
+
+    #[msg("Bounty can only be expired after its deadline and review period, if unawarded.")]
+    BountyNotExpired,
+
+    #[msg("This tier is locked until the wallet has enough claims, account age and referrals.")]
+    TierLocked,
+
+    #[msg("Invalid tier index or a negative account age.")]
+    InvalidTierRequirements,
+
+    #[msg("Grant can only be closed once nothing is left in escrow.")]
+    GrantNotSettled,
+
+    #[msg("Milestone grants must be closed together with their schedule.")]
+    GrantMilestonesRequired,
+
+    #[msg("Campaign can only be closed once its budget has been swept.")]
+    CampaignNotSwept,
+
+    #[msg("Bounty can only be closed once awarded or expired.")]
+    BountyStillOpen,
+
+    #[msg("This leaderboard has been initialized and must be passed.")]
+    LeaderboardRequired,
+
+    #[msg("Account data doesn't match any layout this program can migrate.")]
+    InvalidAccountLayout,
+
+    #[msg("Account is already in the current layout.")]
+    AccountAlreadyMigrated,
 }
\ No newline at end of file
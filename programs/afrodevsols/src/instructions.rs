@@ -1,18 +1,56 @@
 // programs/afrodevsols/src/instructions.rs
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
 use anchor_lang::system_program;
+use solana_instructions_sysvar::get_instruction_relative;
+use solana_sdk_ids::ed25519_program;
 use crate::state::*;
 use crate::errors::AfrodevsError;
 use crate::constants::*;
 use crate::{
     ClaimEvent,
+    SponsoredClaimEvent,
+    CpiClaimEvent,
+    PauseUpdatedEvent,
+    CircuitBreakerTrippedEvent,
+    CampaignCreatedEvent,
+    CampaignClaimEvent,
+    CampaignClosedEvent,
+    CampaignSweptEvent,
+    CampaignAccountClosedEvent,
+    VoucherCreatedEvent,
+    VoucherRedeemedEvent,
+    VoucherClosedEvent,
+    BountyCreatedEvent,
+    BountySubmittedEvent,
+    BountyAwardedEvent,
+    BountyExpiredEvent,
+    BountyClosedEvent,
+    ConfigMigratedEvent,
+    RecordMigratedEvent,
+    ReferralPendingEvent,
     ReferralConfirmedEvent,
+    AncestorReferralBonusEvent,
+    ReferralBonusExpiredEvent,
     ReferralBonusClaimedEvent,
+    ReferralCodeRegisteredEvent,
+    ReferralCodeTransferredEvent,
+    ReferralCodeReleasedEvent,
     SpecialGrantEvent,
+    GrantEscrowedEvent,
+    GrantAcceptedEvent,
+    GrantExpiredEvent,
+    MilestoneGrantCreatedEvent,
+    MilestoneReleasedEvent,
+    MilestoneCancelledEvent,
+    GrantRecordClosedEvent,
     ConfigUpdatedEvent,
     TreasuryFundedEvent,
+    FunderNameUpdatedEvent,
     WithdrawalEvent,
+    TreasuryLowEvent,
+    TreasuryRecoveredEvent,
     WalletBlockedEvent,
 };
 
@@ -61,7 +99,7 @@ pub fn handle_initialize(
 
     config.authority = ctx.accounts.authority.key();
     config.treasury = ctx.accounts.treasury_vault.key();
-    config.is_paused = false;
+    config.paused_features = 0;
     config.program_version = [1, 0, 0];
     config.total_sol_distributed = 0;
     config.total_claims = 0;
@@ -77,6 +115,45 @@ pub fn handle_initialize(
     config.referral_bonus_claimer = referral_bonus_claimer;
     config.referral_bonus_referrer = referral_bonus_referrer;
     config.bump = ctx.bumps.faucet_config;
+    config.referral_min_claims = 1;
+    config.referral_min_days = 0;
+    config.referrer_min_claims = 1;
+    config.referral_ancestor_levels = 0;
+    config.referral_ancestor_bps = 0;
+    config.referral_decay_bps = 0;
+    config.referral_daily_cap = 0;
+    config.referral_lifetime_cap = 0;
+    config.referral_bonus_expiry_days = 0;
+    config.outstanding_referral_bonuses = 0;
+    config.streak_bonus_bps = 0;
+    config.streak_bonus_max_bps = 0;
+    config.total_funds_received = 0;
+    config.total_donations = 0;
+    config.total_unique_funders = 0;
+    config.low_water_mark = 0;
+    config.high_water_mark = 0;
+    config.auto_pause_on_low = false;
+    config.pause_reasons = [PAUSE_REASON_NONE; PAUSE_FEATURE_COUNT];
+    config.scaling_mode = SCALING_MODE_OFF;
+    config.scaling_full_balance = 0;
+    config.scaling_floor_balance = 0;
+    config.scaling_floor_bps = BPS_DENOMINATOR;
+    config.scaling_step_thresholds = [0u64; SCALING_STEP_COUNT];
+    config.scaling_step_bps = [BPS_DENOMINATOR; SCALING_STEP_COUNT];
+    config.sponsored_claims_enabled = false;
+    config.sponsor_relayer = Pubkey::default();
+    config.sponsor_reimbursement = 0;
+    config.sponsor_daily_limit = 0;
+    config.sponsor_reimbursed_today = 0;
+    config.integrator_programs = [Pubkey::default(); MAX_INTEGRATORS];
+    config.circuit_breaker_limit = 0;
+    config.circuit_breaker_window_minutes = OUTFLOW_BUCKET_COUNT as u8;
+    config.outflow_buckets = [0u64; OUTFLOW_BUCKET_COUNT];
+    config.outflow_last_minute = 0;
+    config.grant_nonce = 0;
+    config.bounty_nonce = 0;
+    config.leaderboards_initialized = 0;
+    config.layout_version = CONFIG_LAYOUT_VERSION;
 
     Ok(())
 }
@@ -89,6 +166,7 @@ pub fn handle_initialize(
 #[derive(Accounts)]
 pub struct FundTreasury<'info> {
     #[account(
+        mut,
         seeds = [FAUCET_CONFIG_SEED],
         bump = faucet_config.bump,
     )]
@@ -102,6 +180,23 @@ pub struct FundTreasury<'info> {
     )]
     pub treasury_vault: AccountInfo<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = FunderRecord::LEN,
+        seeds = [FUNDER_SEED, funder.key().as_ref()],
+        bump
+    )]
+    pub funder_record: Account<'info, FunderRecord>,
+
+    /// Required once the donors leaderboard is initialized.
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &[LEADERBOARD_KIND_DONORS]],
+        bump = donors_leaderboard.bump,
+    )]
+    pub donors_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
     #[account(mut)]
     pub funder: Signer<'info>,
 
@@ -110,6 +205,11 @@ pub struct FundTreasury<'info> {
 
 pub fn handle_fund_treasury(ctx: Context<FundTreasury>, amount: u64) -> Result<()> {
     require!(amount > 0, AfrodevsError::InvalidAmount);
+    require_leaderboard(
+        &ctx.accounts.faucet_config,
+        LEADERBOARD_KIND_DONORS,
+        ctx.accounts.donors_leaderboard.is_some(),
+    )?;
 
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -121,12 +221,62 @@ pub fn handle_fund_treasury(ctx: Context<FundTreasury>, amount: u64) -> Result<(
     system_program::transfer(cpi_context, amount)?;
 
     let new_balance = ctx.accounts.treasury_vault.lamports();
+    let timestamp = Clock::get()?.unix_timestamp;
+    let funder = ctx.accounts.funder.key();
+    let config = &mut ctx.accounts.faucet_config;
+    let funder_record = &mut ctx.accounts.funder_record;
+
+    // ── UPDATE FUNDER RECORD ─────────────────────────────────
+
+    if funder_record.donation_count == 0 {
+        funder_record.funder = funder;
+        funder_record.first_donation_at = timestamp;
+        funder_record.bump = ctx.bumps.funder_record;
+        config.total_unique_funders = config.total_unique_funders
+            .checked_add(1)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+
+    funder_record.total_donated = funder_record.total_donated
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    funder_record.donation_count = funder_record.donation_count
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+    funder_record.last_donation_at = timestamp;
+
+    config.total_funds_received = config.total_funds_received
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    config.total_donations = config.total_donations
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    if let Some(board) = ctx.accounts.donors_leaderboard.as_mut() {
+        board.record(funder, funder_record.total_donated, timestamp);
+    }
+
+    // Lift an automatic low-treasury pause once refilled. Features
+    // an admin paused for another reason stay paused.
+    let auto_paused = config.features_paused_for(PAUSE_REASON_LOW_TREASURY);
+    if auto_paused != 0 && new_balance >= config.high_water_mark {
+        config.set_paused(auto_paused, false, PAUSE_REASON_NONE);
+
+        emit!(TreasuryRecoveredEvent {
+            balance: new_balance,
+            high_water_mark: config.high_water_mark,
+            funder,
+            timestamp,
+        });
+    }
 
     emit!(TreasuryFundedEvent {
-        funder: ctx.accounts.funder.key(),
+        funder,
         amount,
         new_balance,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
+        funder_total_donated: funder_record.total_donated,
+        funder_donation_count: funder_record.donation_count,
     });
 
     Ok(())
@@ -163,6 +313,38 @@ pub struct Claim<'info> {
     )]
     pub claimer_record: Account<'info, ClaimerRecord>,
 
+    /// Required when claiming with a referrer, and on every later
+    /// claim by a referred wallet.
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = ReferralRecord::LEN,
+        seeds = [REFERRAL_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub referral_record: Option<Account<'info, ReferralRecord>>,
+
+    /// The referrer's record. Required while the referral is pending
+    /// and credited when it unlocks.
+    #[account(mut)]
+    pub referrer_record: Option<Account<'info, ClaimerRecord>>,
+
+    /// Required once the claimers leaderboard is initialized.
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &[LEADERBOARD_KIND_CLAIMERS]],
+        bump = claimers_leaderboard.bump,
+    )]
+    pub claimers_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
+    /// Required once the referrers leaderboard is initialized.
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &[LEADERBOARD_KIND_REFERRERS]],
+        bump = referrers_leaderboard.bump,
+    )]
+    pub referrers_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
     #[account(mut)]
     pub claimer: Signer<'info>,
 
@@ -174,65 +356,49 @@ pub fn handle_claim(
     amount: u64,
     referrer: Option<Pubkey>,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.faucet_config;
-    let claimer_record = &mut ctx.accounts.claimer_record;
+    process_claim(ctx.accounts, &ctx.bumps, ctx.remaining_accounts, amount, referrer)?;
+    Ok(())
+}
+
+// Shared by every claim entry point. Returns true when the
+// referral was applied. Remaining accounts are the referrer's
+// ancestors' ClaimerRecords, nearest first.
+fn process_claim(
+    accounts: &mut Claim,
+    bumps: &ClaimBumps,
+    ancestors: &[AccountInfo],
+    amount: u64,
+    referrer: Option<Pubkey>,
+) -> Result<bool> {
+    let config = &mut accounts.faucet_config;
+    let claimer_record = &mut accounts.claimer_record;
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
     let current_slot = clock.slot;
 
-    // ── VALIDATION GAUNTLET ──────────────────────────────────
-
-    // 1. Program not paused
-    require!(!config.is_paused, AfrodevsError::FaucetPaused);
-
-    // 2. Amount matches a valid tier
-    let tier_index = config.get_tier_index(amount)
-        .ok_or(AfrodevsError::InvalidAmount)?;
-
-    // 3. Amount within min/max
-    require!(amount >= config.min_amount, AfrodevsError::AmountTooLow);
-    require!(amount <= config.max_amount, AfrodevsError::AmountTooHigh);
-
-    // 4. Reset daily counter if needed
-    if config.is_daily_reset_needed(current_time) {
-        config.daily_global_distributed = 0;
-        config.daily_reset_timestamp = current_time;
-    }
-
-    // 5. Daily global limit not exceeded
-    let projected_daily = config.daily_global_distributed
-        .checked_add(amount)
-        .ok_or(AfrodevsError::Overflow)?;
-    require!(
-        projected_daily <= config.daily_global_limit,
-        AfrodevsError::DailyLimitReached
-    );
-
-    // 6. Treasury has enough (keeping rent reserve)
-    let treasury_balance = ctx.accounts.treasury_vault.lamports();
-    require!(
-        treasury_balance >= amount + RENT_RESERVE_LAMPORTS,
-        AfrodevsError::InsufficientTreasury
-    );
-
-    // 7. Wallet not blocked (only if record already existed)
-    if claimer_record.total_claims > 0 {
-        require!(!claimer_record.is_blocked, AfrodevsError::WalletBlocked);
-    }
+    require_leaderboard(config, LEADERBOARD_KIND_CLAIMERS, accounts.claimers_leaderboard.is_some())?;
+    require_leaderboard(config, LEADERBOARD_KIND_REFERRERS, accounts.referrers_leaderboard.is_some())?;
 
-    // 8. Cooldown for this tier has expired
-    if claimer_record.total_claims > 0 {
-        require!(
-            !claimer_record.is_cooldown_active(tier_index, current_time),
-            AfrodevsError::CooldownActive
-        );
-    }
+    config.roll_daily_counters(current_time);
 
-    // 9. Not same slot as last claim (double-spend prevention)
-    require!(
-        current_slot > claimer_record.last_claim_slot,
-        AfrodevsError::CooldownActive
-    );
+    let treasury_balance = accounts.treasury_vault.lamports();
+    let check = check_claim(
+        config,
+        Some(claimer_record),
+        treasury_balance,
+        amount,
+        current_time,
+        current_slot,
+    )?;
+    let ClaimCheck {
+        tier_index,
+        runway_multiplier_bps,
+        scaled_amount,
+        current_streak,
+        streak_bonus: streak_bonus_applied,
+        spendable,
+        ..
+    } = check;
 
     // ── REFERRAL HANDLING ────────────────────────────────────
 
@@ -243,85 +409,195 @@ pub fn handle_claim(
     if let Some(referrer_key) = referrer {
         if config.referral_enabled
             && is_new_claimer
-            && referrer_key != ctx.accounts.claimer.key()
+            && referrer_key != accounts.claimer.key()
         {
+            // Referrer must be a real, unblocked faucet user
+            let referrer_record = accounts.referrer_record
+                .as_mut()
+                .ok_or(AfrodevsError::InvalidReferral)?;
+            require!(
+                config.is_eligible_referrer(referrer_record, &referrer_key),
+                AfrodevsError::InvalidReferral
+            );
+
+            // Referrer rate limits
+            require!(
+                !config.is_referral_lifetime_cap_reached(referrer_record.referral_count),
+                AfrodevsError::ReferralLifetimeCapReached
+            );
+            let today = DailyStats::day_number(current_time);
+            let referrals_today = referrer_record.referrals_on_day(today);
+            require!(
+                config.referral_daily_cap == 0 || referrals_today < config.referral_daily_cap,
+                AfrodevsError::ReferralDailyCapReached
+            );
+            referrer_record.referral_day = today;
+            referrer_record.referrals_today = referrals_today + 1;
+
+            let referral_record = accounts.referral_record
+                .as_mut()
+                .ok_or(AfrodevsError::ReferralRecordRequired)?;
+
+            // A wallet that closed and re-created its ClaimerRecord
+            // keeps its old ReferralRecord and can't be referred twice.
+            require!(
+                referral_record.referred == Pubkey::default(),
+                AfrodevsError::ReferralAlreadyUsed
+            );
+
             referral_bonus_applied = config.referral_bonus_claimer;
             was_referral = true;
             claimer_record.referred_by = Some(referrer_key);
 
-            emit!(ReferralConfirmedEvent {
+            referral_record.referrer = referrer_key;
+            referral_record.referred = accounts.claimer.key();
+            referral_record.confirmed_at = 0;
+            referral_record.bonus_paid_to_referrer = false;
+            referral_record.bonus_paid_to_referred = true;
+            referral_record.bump = bumps.referral_record.ok_or(AfrodevsError::ReferralRecordRequired)?;
+            referral_record.created_at = current_time;
+            referral_record.referrer_bonus = config.referral_bonus_referrer;
+            referral_record.referred_bonus = referral_bonus_applied;
+
+            emit!(ReferralPendingEvent {
                 referrer: referrer_key,
-                referred: ctx.accounts.claimer.key(),
+                referred: accounts.claimer.key(),
                 timestamp: current_time,
-                bonus_queued_for_referrer: config.referral_bonus_referrer,
-                bonus_applied_to_referred: referral_bonus_applied,
+                claims_required: config.referral_min_claims,
+                unlocks_after: current_time + config.referral_min_seconds(),
             });
         }
     }
 
-    let total_amount = amount
+    // ── STREAK BONUS ─────────────────────────────────────────
+    // Worked out by the gauntlet so it counts toward the daily limit.
+
+    let total_amount = scaled_amount
         .checked_add(referral_bonus_applied)
+        .and_then(|v| v.checked_add(streak_bonus_applied))
         .ok_or(AfrodevsError::Overflow)?;
 
-    // Final treasury check with bonus included
-    require!(
-        treasury_balance >= total_amount + RENT_RESERVE_LAMPORTS,
-        AfrodevsError::InsufficientTreasury
-    );
+    // Final treasury check with bonuses included
+    require!(spendable >= total_amount, AfrodevsError::InsufficientTreasury);
 
     // ── EXECUTE TRANSFER ─────────────────────────────────────
 
-    let treasury_bump = ctx.bumps.treasury_vault;
-    let treasury_seeds: &[&[u8]] = &[TREASURY_VAULT_SEED, &[treasury_bump]];
-    let signer_seeds = &[treasury_seeds];
-
-    let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.treasury_vault.to_account_info(),
-            to: ctx.accounts.claimer.to_account_info(),
-        },
-        signer_seeds,
-    );
-    system_program::transfer(cpi_context, total_amount)?;
+    pay_from_treasury(
+        config,
+        &accounts.treasury_vault,
+        &accounts.claimer.to_account_info(),
+        &accounts.system_program,
+        bumps.treasury_vault,
+        total_amount,
+        current_time,
+    )?;
+    check_velocity(config, total_amount, current_time);
 
     // ── UPDATE STATE ─────────────────────────────────────────
 
-    let new_cooldown_end = current_time + config.cooldown_tier_seconds[tier_index];
+    let new_cooldown_end = record_claim(
+        config,
+        claimer_record,
+        accounts.claimer.key(),
+        bumps.claimer_record,
+        &check,
+        total_amount,
+        current_streak,
+        current_time,
+        current_slot,
+    )?;
 
-    if is_new_claimer {
-        claimer_record.wallet = ctx.accounts.claimer.key();
-        claimer_record.created_at = current_time;
-        claimer_record.cooldown_ends_at = [0i64; 4];
-        claimer_record.bump = ctx.bumps.claimer_record;
-        config.total_unique_claimers = config.total_unique_claimers
-            .checked_add(1)
-            .ok_or(AfrodevsError::Overflow)?;
+    if let Some(board) = accounts.claimers_leaderboard.as_mut() {
+        board.record(accounts.claimer.key(), claimer_record.total_claimed, current_time);
     }
 
-    claimer_record.total_claimed = claimer_record.total_claimed
-        .checked_add(total_amount)
-        .ok_or(AfrodevsError::Overflow)?;
-    claimer_record.total_claims = claimer_record.total_claims
-        .checked_add(1)
-        .ok_or(AfrodevsError::Overflow)?;
-    claimer_record.last_claim_timestamp = current_time;
-    claimer_record.last_claim_amount = total_amount;
-    claimer_record.cooldown_ends_at[tier_index] = new_cooldown_end;
-    claimer_record.last_claim_slot = current_slot;
+    // ── REFERRAL UNLOCK ──────────────────────────────────────
+    // The referrer is only credited once the referred wallet has
+    // made enough claims over enough days. Both records must come
+    // along while the referral is pending, so leaving them out
+    // can't skip the credit.
 
-    config.total_sol_distributed = config.total_sol_distributed
-        .checked_add(total_amount)
-        .ok_or(AfrodevsError::Overflow)?;
-    config.total_claims = config.total_claims
-        .checked_add(1)
-        .ok_or(AfrodevsError::Overflow)?;
-    config.daily_global_distributed = projected_daily;
+    if claimer_record.referred_by.is_some() {
+        let referral_record = accounts.referral_record
+            .as_mut()
+            .ok_or(AfrodevsError::ReferralRecordRequired)?;
+        require!(
+            referral_record.referred == accounts.claimer.key(),
+            AfrodevsError::InvalidReferral
+        );
+
+        if !referral_record.is_confirmed() {
+            let referrer_record = accounts.referrer_record
+                .as_mut()
+                .ok_or(AfrodevsError::ReferralRecordRequired)?;
+            require!(
+                referrer_record.wallet == referral_record.referrer,
+                AfrodevsError::InvalidReferral
+            );
+
+            if !config.is_paused(PAUSE_REFERRAL_CONFIRMATION)
+                && config.is_referral_unlocked(claimer_record, current_time)
+            {
+                // Referrals that were pending when the referrer hit the
+                // lifetime cap or was blocked still confirm, but earn nothing.
+                if referrer_record.is_blocked
+                    || config.is_referral_lifetime_cap_reached(referrer_record.referral_count)
+                {
+                    referral_record.referrer_bonus = 0;
+                } else {
+                    referrer_record.referral_count = referrer_record.referral_count
+                        .checked_add(1)
+                        .ok_or(AfrodevsError::Overflow)?;
+
+                    if let Some(board) = accounts.referrers_leaderboard.as_mut() {
+                        board.record(referrer_record.wallet, referrer_record.referral_count, current_time);
+                    }
+                }
+
+                let mut bonus_queued = referral_record.referrer_bonus;
+                if bonus_queued > 0 {
+                    queue_referral_bonus(
+                        config,
+                        referrer_record,
+                        bonus_queued,
+                        referral_record.referred,
+                        current_time,
+                    )?;
+                }
+                referral_record.confirmed_at = current_time;
+
+                emit!(ReferralConfirmedEvent {
+                    referrer: referral_record.referrer,
+                    referred: referral_record.referred,
+                    timestamp: current_time,
+                    bonus_queued_for_referrer: referral_record.referrer_bonus,
+                    bonus_applied_to_referred: referral_record.referred_bonus,
+                });
+
+                if bonus_queued > 0 {
+                    bonus_queued = bonus_queued
+                        .checked_add(credit_referral_ancestors(
+                            config,
+                            referrer_record,
+                            referral_record.referrer_bonus,
+                            referral_record.referred,
+                            ancestors,
+                            current_time,
+                        )?)
+                        .ok_or(AfrodevsError::Overflow)?;
+                }
+
+                config.outstanding_referral_bonuses = config.outstanding_referral_bonuses
+                    .checked_add(bonus_queued)
+                    .ok_or(AfrodevsError::Overflow)?;
+            }
+        }
+    }
 
     // ── EMIT EVENT ───────────────────────────────────────────
 
     emit!(ClaimEvent {
-        claimer: ctx.accounts.claimer.key(),
+        claimer: accounts.claimer.key(),
         amount: total_amount,
         timestamp: current_time,
         claimer_total: claimer_record.total_claimed,
@@ -330,9 +606,15 @@ pub fn handle_claim(
         tier_index: tier_index as u8,
         was_referral,
         referral_bonus_applied,
+        current_streak: claimer_record.current_streak,
+        longest_streak: claimer_record.longest_streak,
+        streak_bonus_applied,
+        base_amount: amount,
+        scaled_amount,
+        runway_multiplier_bps,
     });
 
-    Ok(())
+    Ok(was_referral)
 }
 
 // ============================================================
@@ -343,6 +625,7 @@ pub fn handle_claim(
 #[derive(Accounts)]
 pub struct ClaimReferralBonus<'info> {
     #[account(
+        mut,
         seeds = [FAUCET_CONFIG_SEED],
         bump = faucet_config.bump,
     )]
@@ -370,14 +653,18 @@ pub struct ClaimReferralBonus<'info> {
 }
 
 pub fn handle_claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<()> {
-    let config = &ctx.accounts.faucet_config;
+    let config = &mut ctx.accounts.faucet_config;
     let referrer_record = &mut ctx.accounts.referrer_record;
     let clock = Clock::get()?;
 
-    require!(!config.is_paused, AfrodevsError::FaucetPaused);
+    require!(!config.is_paused(PAUSE_REFERRAL_PAYOUT), AfrodevsError::ReferralPayoutPaused);
 
     let bonus_amount = referrer_record.pending_referral_bonus;
     require!(bonus_amount > 0, AfrodevsError::NoPendingBonus);
+    require!(
+        !config.is_referral_bonus_expired(referrer_record, clock.unix_timestamp),
+        AfrodevsError::ReferralBonusExpired
+    );
 
     let treasury_balance = ctx.accounts.treasury_vault.lamports();
     require!(
@@ -387,21 +674,20 @@ pub fn handle_claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<(
 
     // ── EXECUTE TRANSFER ─────────────────────────────────────
 
-    let treasury_bump = ctx.bumps.treasury_vault;
-    let treasury_seeds: &[&[u8]] = &[TREASURY_VAULT_SEED, &[treasury_bump]];
-    let signer_seeds = &[treasury_seeds];
-
-    let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.treasury_vault.to_account_info(),
-            to: ctx.accounts.referrer.to_account_info(),
-        },
-        signer_seeds,
-    );
-    system_program::transfer(cpi_context, bonus_amount)?;
+    pay_from_treasury(
+        config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.referrer.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        bonus_amount,
+        clock.unix_timestamp,
+    )?;
 
     referrer_record.pending_referral_bonus = 0;
+    referrer_record.pending_bonus_since = 0;
+    config.outstanding_referral_bonuses = config.outstanding_referral_bonuses
+        .saturating_sub(bonus_amount);
 
     emit!(ReferralBonusClaimedEvent {
         referrer: ctx.accounts.referrer.key(),
@@ -415,7 +701,7 @@ pub fn handle_claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<(
 // ============================================================
 // INSTRUCTION 5: SPECIAL GRANT
 // Admin sends any amount to one wallet. No rules apply.
-// Uses total_claims as nonce so admin can grant same recipient multiple times.
+// Uses grant_nonce as nonce so admin can grant same recipient multiple times.
 // ============================================================
 
 #[derive(Accounts)]
@@ -449,7 +735,7 @@ pub struct SpecialGrant<'info> {
             GRANT_RECORD_SEED,
             authority.key().as_ref(),
             recipient.as_ref(),
-            &faucet_config.total_claims.to_le_bytes(),
+            &faucet_config.grant_nonce.to_le_bytes(),
         ],
         bump
     )]
@@ -468,52 +754,51 @@ pub fn handle_special_grant(
     reason: String,
     is_public: bool,
 ) -> Result<()> {
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
     require!(amount > 0, AfrodevsError::InvalidAmount);
     require!(!reason.is_empty(), AfrodevsError::EmptyReason);
     require!(reason.len() <= MAX_REASON_LENGTH, AfrodevsError::EmptyReason);
 
     let treasury_balance = ctx.accounts.treasury_vault.lamports();
     require!(
-        treasury_balance >= amount + RENT_RESERVE_LAMPORTS,
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= amount,
         AfrodevsError::InsufficientTreasury
     );
+    let timestamp = Clock::get()?.unix_timestamp;
 
     // ── EXECUTE TRANSFER ─────────────────────────────────────
 
-    let treasury_bump = ctx.bumps.treasury_vault;
-    let treasury_seeds: &[&[u8]] = &[TREASURY_VAULT_SEED, &[treasury_bump]];
-    let signer_seeds = &[treasury_seeds];
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.recipient_wallet.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        amount,
+        timestamp,
+    )?;
 
-    let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.treasury_vault.to_account_info(),
-            to: ctx.accounts.recipient_wallet.to_account_info(),
-        },
-        signer_seeds,
-    );
-    system_program::transfer(cpi_context, amount)?;
+    let config = &mut ctx.accounts.faucet_config;
+    config.grant_nonce = config.grant_nonce
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
 
     // ── WRITE GRANT RECORD ────────────────────────────────────
 
     let grant = &mut ctx.accounts.grant_record;
-    let clock = Clock::get()?;
-    let timestamp = clock.unix_timestamp;
 
     grant.authority = ctx.accounts.authority.key();
     grant.recipient = recipient;
     grant.amount = amount;
     grant.timestamp = timestamp;
-    grant.grant_type = 0;
+    grant.grant_type = GRANT_TYPE_SPECIAL;
     grant.batch_id = 0;
     grant.is_public = is_public;
     grant.bump = ctx.bumps.grant_record;
-
-    let mut reason_bytes = [0u8; 64];
-    let reason_slice = reason.as_bytes();
-    let copy_len = reason_slice.len().min(64);
-    reason_bytes[..copy_len].copy_from_slice(&reason_slice[..copy_len]);
-    grant.reason = reason_bytes;
+    grant.reason = GrantRecord::encode_reason(&reason);
+    grant.status = GRANT_STATUS_PAID;
+    grant.expires_at = 0;
+    grant.settled_at = timestamp;
 
     let new_treasury_balance = ctx.accounts.treasury_vault.lamports();
 
@@ -548,9 +833,30 @@ pub struct UpdateConfig<'info> {
     pub authority: Signer<'info>,
 }
 
+// Sets `config.field` (or one slot of it) from an optional new value
+// and records the field's name in `changed` when the value actually
+// moves. Resubmitting a current value is not a change.
+macro_rules! set_field {
+    ($config:expr, $changed:expr, $value:expr, $field:ident) => {
+        if let Some(v) = $value {
+            if $config.$field != v {
+                $config.$field = v;
+                $changed.push(String::from(stringify!($field)));
+            }
+        }
+    };
+    ($config:expr, $changed:expr, $value:expr, $field:ident[$index:expr]) => {
+        if let Some(v) = $value {
+            if $config.$field[$index] != v {
+                $config.$field[$index] = v;
+                $changed.push(format!("{}[{}]", stringify!($field), $index));
+            }
+        }
+    };
+}
+
 pub fn handle_update_config(
     ctx: Context<UpdateConfig>,
-    is_paused: Option<bool>,
     min_amount: Option<u64>,
     max_amount: Option<u64>,
     cooldown_tier_amounts: Option<[u64; 4]>,
@@ -563,27 +869,19 @@ pub fn handle_update_config(
 ) -> Result<()> {
     let config = &mut ctx.accounts.faucet_config;
     let clock = Clock::get()?;
-    let mut field_changed = String::from("multiple");
-
-    if let Some(v) = is_paused {
-        config.is_paused = v;
-        field_changed = String::from("is_paused");
-    }
-    if let Some(v) = min_amount { config.min_amount = v; }
-    if let Some(v) = max_amount { config.max_amount = v; }
-    if let Some(v) = cooldown_tier_amounts { config.cooldown_tier_amounts = v; }
-    if let Some(v) = cooldown_tier_seconds { config.cooldown_tier_seconds = v; }
-    if let Some(v) = daily_global_limit { config.daily_global_limit = v; }
-    if let Some(v) = referral_enabled { config.referral_enabled = v; }
-    if let Some(v) = referral_bonus_claimer { config.referral_bonus_claimer = v; }
-    if let Some(v) = referral_bonus_referrer { config.referral_bonus_referrer = v; }
-    if let Some(v) = new_authority { config.authority = v; }
 
-    emit!(ConfigUpdatedEvent {
-        authority: ctx.accounts.authority.key(),
-        timestamp: clock.unix_timestamp,
-        field_changed,
-    });
+    let mut changed = Vec::new();
+    set_field!(config, changed, min_amount, min_amount);
+    set_field!(config, changed, max_amount, max_amount);
+    set_field!(config, changed, cooldown_tier_amounts, cooldown_tier_amounts);
+    set_field!(config, changed, cooldown_tier_seconds, cooldown_tier_seconds);
+    set_field!(config, changed, daily_global_limit, daily_global_limit);
+    set_field!(config, changed, referral_enabled, referral_enabled);
+    set_field!(config, changed, referral_bonus_claimer, referral_bonus_claimer);
+    set_field!(config, changed, referral_bonus_referrer, referral_bonus_referrer);
+    set_field!(config, changed, new_authority, authority);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
 
     Ok(())
 }
@@ -638,6 +936,7 @@ pub fn handle_block_wallet(
 #[derive(Accounts)]
 pub struct WithdrawTreasury<'info> {
     #[account(
+        mut,
         seeds = [FAUCET_CONFIG_SEED],
         bump = faucet_config.bump,
         has_one = authority @ AfrodevsError::Unauthorized,
@@ -662,6 +961,10 @@ pub fn handle_withdraw_treasury(
     ctx: Context<WithdrawTreasury>,
     amount: u64,
 ) -> Result<()> {
+    require!(
+        !ctx.accounts.faucet_config.is_paused(PAUSE_WITHDRAWALS),
+        AfrodevsError::WithdrawalsPaused
+    );
     require!(amount > 0, AfrodevsError::InvalidAmount);
 
     let treasury_balance = ctx.accounts.treasury_vault.lamports();
@@ -669,22 +972,23 @@ pub fn handle_withdraw_treasury(
         treasury_balance >= amount + RENT_RESERVE_LAMPORTS,
         AfrodevsError::RentReserveViolation
     );
+    require!(
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= amount,
+        AfrodevsError::LiabilityReserveViolation
+    );
+    let timestamp = Clock::get()?.unix_timestamp;
 
     // ── EXECUTE TRANSFER ─────────────────────────────────────
 
-    let treasury_bump = ctx.bumps.treasury_vault;
-    let treasury_seeds: &[&[u8]] = &[TREASURY_VAULT_SEED, &[treasury_bump]];
-    let signer_seeds = &[treasury_seeds];
-
-    let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.treasury_vault.to_account_info(),
-            to: ctx.accounts.authority.to_account_info(),
-        },
-        signer_seeds,
-    );
-    system_program::transfer(cpi_context, amount)?;
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        amount,
+        timestamp,
+    )?;
 
     let new_balance = ctx.accounts.treasury_vault.lamports();
 
@@ -693,7 +997,7 @@ pub fn handle_withdraw_treasury(
         amount,
         destination: ctx.accounts.authority.key(),
         new_balance,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
     });
 
     Ok(())
@@ -743,4 +1047,3185 @@ pub fn handle_close_claimer_record(
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+
+// ============================================================
+// INSTRUCTION 10: ESCROW GRANT
+// Admin parks a grant in its GrantRecord. The recipient must
+// sign accept_grant before expiry, so typos never lose funds.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct EscrowGrant<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GrantRecord::LEN,
+        seeds = [
+            GRANT_RECORD_SEED,
+            authority.key().as_ref(),
+            recipient.as_ref(),
+            &faucet_config.grant_nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_escrow_grant(
+    ctx: Context<EscrowGrant>,
+    recipient: Pubkey,
+    amount: u64,
+    reason: String,
+    is_public: bool,
+    expires_in: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
+    require!(amount > 0, AfrodevsError::InvalidAmount);
+    require!(!reason.is_empty(), AfrodevsError::EmptyReason);
+    require!(reason.len() <= MAX_REASON_LENGTH, AfrodevsError::EmptyReason);
+    require!(
+        (MIN_GRANT_EXPIRY_SECONDS..=MAX_GRANT_EXPIRY_SECONDS).contains(&expires_in),
+        AfrodevsError::InvalidGrantExpiry
+    );
+
+    let treasury_balance = ctx.accounts.treasury_vault.lamports();
+    require!(
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= amount,
+        AfrodevsError::InsufficientTreasury
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // ── MOVE FUNDS INTO ESCROW ───────────────────────────────
+
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.grant_record.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        amount,
+        timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.faucet_config;
+    config.grant_nonce = config.grant_nonce
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    // ── WRITE GRANT RECORD ────────────────────────────────────
+
+    let grant = &mut ctx.accounts.grant_record;
+    let expires_at = timestamp
+        .checked_add(expires_in)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    grant.authority = ctx.accounts.authority.key();
+    grant.recipient = recipient;
+    grant.amount = amount;
+    grant.reason = GrantRecord::encode_reason(&reason);
+    grant.timestamp = timestamp;
+    grant.grant_type = GRANT_TYPE_SPECIAL;
+    grant.batch_id = 0;
+    grant.is_public = is_public;
+    grant.bump = ctx.bumps.grant_record;
+    grant.status = GRANT_STATUS_PENDING;
+    grant.expires_at = expires_at;
+    grant.settled_at = 0;
+
+    emit!(GrantEscrowedEvent {
+        grant: grant.key(),
+        recipient,
+        amount,
+        reason,
+        is_public,
+        authority: ctx.accounts.authority.key(),
+        expires_at,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 11: ACCEPT GRANT
+// Recipient signs to pull an escrowed grant.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct AcceptGrant<'info> {
+    #[account(
+        mut,
+        has_one = recipient @ AfrodevsError::Unauthorized,
+        constraint = grant_record.status == GRANT_STATUS_PENDING @ AfrodevsError::GrantNotPending,
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+pub fn handle_accept_grant(ctx: Context<AcceptGrant>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let grant_info = ctx.accounts.grant_record.to_account_info();
+    let grant = &mut ctx.accounts.grant_record;
+
+    require!(!grant.is_expired(current_time), AfrodevsError::GrantExpired);
+
+    release_escrow(&grant_info, &ctx.accounts.recipient.to_account_info(), grant.amount)?;
+
+    grant.status = GRANT_STATUS_ACCEPTED;
+    grant.settled_at = current_time;
+
+    emit!(GrantAcceptedEvent {
+        grant: grant.key(),
+        recipient: grant.recipient,
+        amount: grant.amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 12: EXPIRE GRANT
+// Permissionless crank. Returns an unaccepted grant to the
+// treasury once its expiry has passed.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ExpireGrant<'info> {
+    #[account(
+        mut,
+        constraint = grant_record.status == GRANT_STATUS_PENDING @ AfrodevsError::GrantNotPending,
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    /// CHECK: Treasury vault PDA — receives the escrowed SOL
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    pub cranker: Signer<'info>,
+}
+
+pub fn handle_expire_grant(ctx: Context<ExpireGrant>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let grant_info = ctx.accounts.grant_record.to_account_info();
+    let grant = &mut ctx.accounts.grant_record;
+
+    require!(grant.is_expired(current_time), AfrodevsError::GrantNotExpired);
+
+    release_escrow(&grant_info, &ctx.accounts.treasury_vault, grant.amount)?;
+
+    grant.status = GRANT_STATUS_EXPIRED;
+    grant.settled_at = current_time;
+
+    emit!(GrantExpiredEvent {
+        grant: grant.key(),
+        recipient: grant.recipient,
+        amount: grant.amount,
+        cranked_by: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        new_treasury_balance: ctx.accounts.treasury_vault.lamports(),
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 13: CREATE MILESTONE GRANT
+// Admin escrows the full grant up front and lists the tranches
+// it will be released in.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct CreateMilestoneGrant<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GrantRecord::LEN,
+        seeds = [
+            GRANT_RECORD_SEED,
+            authority.key().as_ref(),
+            recipient.as_ref(),
+            &faucet_config.grant_nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GrantMilestones::LEN,
+        seeds = [GRANT_MILESTONES_SEED, grant_record.key().as_ref()],
+        bump
+    )]
+    pub grant_milestones: Box<Account<'info, GrantMilestones>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_milestone_grant(
+    ctx: Context<CreateMilestoneGrant>,
+    recipient: Pubkey,
+    reason: String,
+    is_public: bool,
+    milestone_amounts: Vec<u64>,
+    description_hashes: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
+    require!(!reason.is_empty(), AfrodevsError::EmptyReason);
+    require!(reason.len() <= MAX_REASON_LENGTH, AfrodevsError::EmptyReason);
+    require!(
+        !milestone_amounts.is_empty()
+            && milestone_amounts.len() <= MAX_MILESTONES
+            && milestone_amounts.len() == description_hashes.len(),
+        AfrodevsError::InvalidMilestones
+    );
+
+    let mut total_amount: u64 = 0;
+    for amount in milestone_amounts.iter() {
+        require!(*amount > 0, AfrodevsError::InvalidAmount);
+        total_amount = total_amount
+            .checked_add(*amount)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+
+    let treasury_balance = ctx.accounts.treasury_vault.lamports();
+    require!(
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= total_amount,
+        AfrodevsError::InsufficientTreasury
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // ── MOVE FUNDS INTO ESCROW ───────────────────────────────
+
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.grant_record.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        total_amount,
+        timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.faucet_config;
+    config.grant_nonce = config.grant_nonce
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    // ── WRITE GRANT RECORD + SCHEDULE ─────────────────────────
+
+    let grant = &mut ctx.accounts.grant_record;
+
+    grant.authority = ctx.accounts.authority.key();
+    grant.recipient = recipient;
+    grant.amount = total_amount;
+    grant.reason = GrantRecord::encode_reason(&reason);
+    grant.timestamp = timestamp;
+    grant.grant_type = GRANT_TYPE_MILESTONE;
+    grant.batch_id = 0;
+    grant.is_public = is_public;
+    grant.bump = ctx.bumps.grant_record;
+    grant.status = GRANT_STATUS_IN_PROGRESS;
+    grant.expires_at = 0;
+    grant.settled_at = 0;
+
+    let milestones = &mut ctx.accounts.grant_milestones;
+    milestones.grant = grant.key();
+    milestones.milestone_count = milestone_amounts.len() as u8;
+    milestones.amounts = [0u64; MAX_MILESTONES];
+    milestones.description_hashes = [[0u8; 32]; MAX_MILESTONES];
+    milestones.released_at = [0i64; MAX_MILESTONES];
+    milestones.amounts[..milestone_amounts.len()].copy_from_slice(&milestone_amounts);
+    milestones.description_hashes[..description_hashes.len()].copy_from_slice(&description_hashes);
+    milestones.released_total = 0;
+    milestones.bump = ctx.bumps.grant_milestones;
+
+    emit!(MilestoneGrantCreatedEvent {
+        grant: grant.key(),
+        recipient,
+        total_amount,
+        milestone_count: milestones.milestone_count,
+        reason,
+        is_public,
+        authority: ctx.accounts.authority.key(),
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 14: APPROVE MILESTONE
+// Admin releases one tranche from escrow to the recipient.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        constraint = grant_record.status == GRANT_STATUS_IN_PROGRESS @ AfrodevsError::GrantNotInProgress,
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(
+        mut,
+        seeds = [GRANT_MILESTONES_SEED, grant_record.key().as_ref()],
+        bump = grant_milestones.bump,
+    )]
+    pub grant_milestones: Box<Account<'info, GrantMilestones>>,
+
+    /// CHECK: The grant recipient — receives SOL
+    #[account(
+        mut,
+        address = grant_record.recipient @ AfrodevsError::Unauthorized,
+    )]
+    pub recipient_wallet: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_approve_milestone(
+    ctx: Context<ApproveMilestone>,
+    milestone_index: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
+
+    let grant_info = ctx.accounts.grant_record.to_account_info();
+    let grant = &mut ctx.accounts.grant_record;
+    let milestones = &mut ctx.accounts.grant_milestones;
+    let index = milestone_index as usize;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        index < milestones.milestone_count as usize,
+        AfrodevsError::InvalidMilestoneIndex
+    );
+    require!(!milestones.is_released(index), AfrodevsError::MilestoneAlreadyReleased);
+
+    let amount = milestones.amounts[index];
+    release_escrow(&grant_info, &ctx.accounts.recipient_wallet, amount)?;
+
+    milestones.released_at[index] = current_time;
+    milestones.released_total = milestones.released_total
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    if milestones.all_released() {
+        grant.status = GRANT_STATUS_COMPLETED;
+        grant.settled_at = current_time;
+    }
+
+    emit!(MilestoneReleasedEvent {
+        grant: grant.key(),
+        recipient: grant.recipient,
+        milestone_index,
+        amount,
+        description_hash: milestones.description_hashes[index],
+        released_total: milestones.released_total,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 15: CANCEL MILESTONE GRANT
+// Admin returns every unreleased tranche to the treasury.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CancelMilestoneGrant<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA — receives the unreleased SOL
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = grant_record.status == GRANT_STATUS_IN_PROGRESS @ AfrodevsError::GrantNotInProgress,
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(
+        seeds = [GRANT_MILESTONES_SEED, grant_record.key().as_ref()],
+        bump = grant_milestones.bump,
+    )]
+    pub grant_milestones: Box<Account<'info, GrantMilestones>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_cancel_milestone_grant(ctx: Context<CancelMilestoneGrant>) -> Result<()> {
+    let grant_info = ctx.accounts.grant_record.to_account_info();
+    let grant = &mut ctx.accounts.grant_record;
+    let milestones = &ctx.accounts.grant_milestones;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let outstanding = milestones.outstanding_amount();
+    release_escrow(&grant_info, &ctx.accounts.treasury_vault, outstanding)?;
+
+    grant.status = GRANT_STATUS_CANCELLED;
+    grant.settled_at = current_time;
+
+    for index in 0..milestones.milestone_count as usize {
+        if milestones.is_released(index) {
+            continue;
+        }
+        emit!(MilestoneCancelledEvent {
+            grant: grant.key(),
+            recipient: grant.recipient,
+            milestone_index: index as u8,
+            amount: milestones.amounts[index],
+            authority: ctx.accounts.authority.key(),
+            timestamp: current_time,
+        });
+    }
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 16: REGISTER REFERRAL CODE
+// Any wallet can reserve an unused short code.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct RegisterReferralCode<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralCode::LEN,
+        seeds = [REFERRAL_CODE_SEED, code.as_bytes()],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_referral_code(
+    ctx: Context<RegisterReferralCode>,
+    code: String,
+) -> Result<()> {
+    require!(ReferralCode::is_valid_code(&code), AfrodevsError::InvalidReferralCode);
+
+    let referral_code = &mut ctx.accounts.referral_code;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    referral_code.owner = ctx.accounts.owner.key();
+    referral_code.code = ReferralCode::encode_code(&code);
+    referral_code.created_at = timestamp;
+    referral_code.times_used = 0;
+    referral_code.bump = ctx.bumps.referral_code;
+
+    emit!(ReferralCodeRegisteredEvent {
+        code,
+        owner: referral_code.owner,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 17: CLAIM WITH CODE
+// Same as claim, but the referrer is resolved from a code.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64, code: String)]
+pub struct ClaimWithCode<'info> {
+    pub claim: Claim<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_CODE_SEED, code.as_bytes()],
+        bump = referral_code.bump,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+}
+
+pub fn handle_claim_with_code(
+    ctx: Context<ClaimWithCode>,
+    amount: u64,
+    _code: String,
+) -> Result<()> {
+    let referrer = ctx.accounts.referral_code.owner;
+    let was_referral = process_claim(
+        &mut ctx.accounts.claim,
+        &ctx.bumps.claim,
+        ctx.remaining_accounts,
+        amount,
+        Some(referrer),
+    )?;
+
+    if was_referral {
+        let referral_code = &mut ctx.accounts.referral_code;
+        referral_code.times_used = referral_code.times_used
+            .checked_add(1)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 18: TRANSFER REFERRAL CODE
+// Current owner hands the code to another wallet.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct TransferReferralCode<'info> {
+    #[account(
+        mut,
+        seeds = [REFERRAL_CODE_SEED, code.as_bytes()],
+        bump = referral_code.bump,
+        has_one = owner @ AfrodevsError::Unauthorized,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_transfer_referral_code(
+    ctx: Context<TransferReferralCode>,
+    code: String,
+    new_owner: Pubkey,
+) -> Result<()> {
+    ctx.accounts.referral_code.owner = new_owner;
+
+    emit!(ReferralCodeTransferredEvent {
+        code,
+        previous_owner: ctx.accounts.owner.key(),
+        new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 19: RELEASE REFERRAL CODE
+// Owner frees the code for others and reclaims the rent.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct ReleaseReferralCode<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [REFERRAL_CODE_SEED, code.as_bytes()],
+        bump = referral_code.bump,
+        has_one = owner @ AfrodevsError::Unauthorized,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_release_referral_code(
+    ctx: Context<ReleaseReferralCode>,
+    code: String,
+) -> Result<()> {
+    emit!(ReferralCodeReleasedEvent {
+        code,
+        owner: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 20: UPDATE REFERRAL CONFIG
+// Admin tunes who may refer and when a referral unlocks the
+// referrer's bonus.
+// ============================================================
+
+pub fn handle_update_referral_config(
+    ctx: Context<UpdateConfig>,
+    referral_min_claims: Option<u64>,
+    referral_min_days: Option<u64>,
+    referrer_min_claims: Option<u64>,
+    referral_ancestor_levels: Option<u8>,
+    referral_ancestor_bps: Option<u16>,
+    referral_decay_bps: Option<u16>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    if let Some(v) = referral_min_claims {
+        require!(v > 0, AfrodevsError::InvalidAmount);
+    }
+    if let Some(v) = referral_ancestor_levels {
+        require!(v as usize <= MAX_REFERRAL_LEVELS, AfrodevsError::TooManyReferralLevels);
+    }
+    for bps in [referral_ancestor_bps, referral_decay_bps].into_iter().flatten() {
+        require!(bps <= BPS_DENOMINATOR, AfrodevsError::InvalidBasisPoints);
+    }
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, referral_min_claims, referral_min_claims);
+    set_field!(config, changed, referral_min_days, referral_min_days);
+    set_field!(config, changed, referrer_min_claims, referrer_min_claims);
+    set_field!(config, changed, referral_ancestor_levels, referral_ancestor_levels);
+    set_field!(config, changed, referral_ancestor_bps, referral_ancestor_bps);
+    set_field!(config, changed, referral_decay_bps, referral_decay_bps);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 21: UPDATE REFERRAL LIMITS
+// Admin caps how fast referrers earn and how long bonuses last.
+// 0 disables a limit.
+// ============================================================
+
+pub fn handle_update_referral_limits(
+    ctx: Context<UpdateConfig>,
+    referral_daily_cap: Option<u64>,
+    referral_lifetime_cap: Option<u64>,
+    referral_bonus_expiry_days: Option<u64>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, referral_daily_cap, referral_daily_cap);
+    set_field!(config, changed, referral_lifetime_cap, referral_lifetime_cap);
+    set_field!(config, changed, referral_bonus_expiry_days, referral_bonus_expiry_days);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 22: EXPIRE REFERRAL BONUS
+// Permissionless crank. Forfeits a referrer's uncollected bonus
+// once it is older than the configured expiry.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(target_wallet: Pubkey)]
+pub struct ExpireReferralBonus<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [CLAIMER_SEED, target_wallet.as_ref()],
+        bump = referrer_record.bump,
+    )]
+    pub referrer_record: Account<'info, ClaimerRecord>,
+
+    pub cranker: Signer<'info>,
+}
+
+pub fn handle_expire_referral_bonus(
+    ctx: Context<ExpireReferralBonus>,
+    target_wallet: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let referrer_record = &mut ctx.accounts.referrer_record;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let expired_amount = referrer_record.pending_referral_bonus;
+    require!(expired_amount > 0, AfrodevsError::NoPendingBonus);
+    require!(
+        config.is_referral_bonus_expired(referrer_record, current_time),
+        AfrodevsError::ReferralBonusNotExpired
+    );
+
+    referrer_record.pending_referral_bonus = 0;
+    referrer_record.pending_bonus_since = 0;
+    config.outstanding_referral_bonuses = config.outstanding_referral_bonuses
+        .saturating_sub(expired_amount);
+
+    emit!(ReferralBonusExpiredEvent {
+        referrer: target_wallet,
+        amount: expired_amount,
+        cranked_by: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 23: UPDATE STREAK CONFIG
+// Admin sets the per-day streak bonus and its cap.
+// ============================================================
+
+pub fn handle_update_streak_config(
+    ctx: Context<UpdateConfig>,
+    streak_bonus_bps: Option<u16>,
+    streak_bonus_max_bps: Option<u16>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    for bps in [streak_bonus_bps, streak_bonus_max_bps].into_iter().flatten() {
+        require!(bps <= BPS_DENOMINATOR, AfrodevsError::InvalidBasisPoints);
+    }
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, streak_bonus_bps, streak_bonus_bps);
+    set_field!(config, changed, streak_bonus_max_bps, streak_bonus_max_bps);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 24: INITIALIZE LEADERBOARD
+// Admin creates one of the fixed-size leaderboards. From then
+// on the instructions that feed it must pass it.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(kind: u8)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Leaderboard::LEN,
+        seeds = [LEADERBOARD_SEED, &[kind]],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_leaderboard(
+    ctx: Context<InitializeLeaderboard>,
+    kind: u8,
+) -> Result<()> {
+    require!(kind <= LEADERBOARD_KIND_DONORS, AfrodevsError::InvalidLeaderboardKind);
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.kind = kind;
+    leaderboard.entries = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    ctx.accounts.faucet_config.leaderboards_initialized |= 1 << kind;
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 25: SET FUNDER DISPLAY NAME
+// Funder chooses how they are credited publicly. An empty
+// name keeps them anonymous.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SetFunderDisplayName<'info> {
+    #[account(
+        mut,
+        seeds = [FUNDER_SEED, funder.key().as_ref()],
+        bump = funder_record.bump,
+        has_one = funder @ AfrodevsError::Unauthorized,
+    )]
+    pub funder_record: Account<'info, FunderRecord>,
+
+    pub funder: Signer<'info>,
+}
+
+pub fn handle_set_funder_display_name(
+    ctx: Context<SetFunderDisplayName>,
+    display_name: String,
+) -> Result<()> {
+    require!(display_name.len() <= MAX_NAME_LENGTH, AfrodevsError::NameTooLong);
+
+    ctx.accounts.funder_record.display_name = pad_bytes(&display_name);
+
+    emit!(FunderNameUpdatedEvent {
+        funder: ctx.accounts.funder.key(),
+        display_name,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 26: UPDATE TREASURY ALERTS
+// Admin sets the low/high-water marks and auto-pause switch.
+// ============================================================
+
+pub fn handle_update_treasury_alerts(
+    ctx: Context<UpdateConfig>,
+    low_water_mark: Option<u64>,
+    high_water_mark: Option<u64>,
+    auto_pause_on_low: Option<bool>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, low_water_mark, low_water_mark);
+    set_field!(config, changed, high_water_mark, high_water_mark);
+    set_field!(config, changed, auto_pause_on_low, auto_pause_on_low);
+
+    require!(
+        config.high_water_mark >= config.low_water_mark,
+        AfrodevsError::InvalidWaterMarks
+    );
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 27: UPDATE RUNWAY SCALING
+// Admin configures how payouts shrink as the treasury drains.
+// ============================================================
+
+pub fn handle_update_runway_scaling(
+    ctx: Context<UpdateConfig>,
+    scaling_mode: Option<u8>,
+    scaling_full_balance: Option<u64>,
+    scaling_floor_balance: Option<u64>,
+    scaling_floor_bps: Option<u16>,
+    scaling_step_thresholds: Option<[u64; 4]>,
+    scaling_step_bps: Option<[u16; 4]>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    if let Some(v) = scaling_mode {
+        require!(v <= SCALING_MODE_STEPS, AfrodevsError::InvalidScalingConfig);
+    }
+    if let Some(v) = scaling_floor_bps {
+        require!(v <= BPS_DENOMINATOR, AfrodevsError::InvalidBasisPoints);
+    }
+    if let Some(v) = scaling_step_bps {
+        require!(v.iter().all(|bps| *bps <= BPS_DENOMINATOR), AfrodevsError::InvalidBasisPoints);
+    }
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, scaling_mode, scaling_mode);
+    set_field!(config, changed, scaling_full_balance, scaling_full_balance);
+    set_field!(config, changed, scaling_floor_balance, scaling_floor_balance);
+    set_field!(config, changed, scaling_floor_bps, scaling_floor_bps);
+    set_field!(config, changed, scaling_step_thresholds, scaling_step_thresholds);
+    set_field!(config, changed, scaling_step_bps, scaling_step_bps);
+
+    require!(
+        config.scaling_full_balance >= config.scaling_floor_balance,
+        AfrodevsError::InvalidScalingConfig
+    );
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 28: UPDATE SPONSOR CONFIG
+// Admin configures gasless claims and relayer refunds.
+// ============================================================
+
+pub fn handle_update_sponsor_config(
+    ctx: Context<UpdateConfig>,
+    enabled: Option<bool>,
+    relayer: Option<Pubkey>,
+    reimbursement: Option<u64>,
+    daily_limit: Option<u64>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, enabled, sponsored_claims_enabled);
+    set_field!(config, changed, relayer, sponsor_relayer);
+    set_field!(config, changed, reimbursement, sponsor_reimbursement);
+    set_field!(config, changed, daily_limit, sponsor_daily_limit);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 29: SPONSOR CLAIMER RECORD
+// A relayer pays rent for a new claimer's records. Sent in the
+// same transaction as sponsored_claim so the claimer needs no SOL.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SponsorClaimerRecord<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = ClaimerRecord::LEN,
+        seeds = [CLAIMER_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_record: Account<'info, ClaimerRecord>,
+
+    /// Only needed when the first claim uses a referrer.
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = ReferralRecord::LEN,
+        seeds = [REFERRAL_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub referral_record: Option<Account<'info, ReferralRecord>>,
+
+    pub claimer: Signer<'info>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_sponsor_claimer_record(ctx: Context<SponsorClaimerRecord>) -> Result<()> {
+    require!(
+        ctx.accounts.faucet_config.sponsored_claims_enabled,
+        AfrodevsError::SponsoredClaimsDisabled
+    );
+
+    // The rest of the record is filled in by the first claim
+    let claimer_record = &mut ctx.accounts.claimer_record;
+    claimer_record.wallet = ctx.accounts.claimer.key();
+    claimer_record.bump = ctx.bumps.claimer_record;
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 30: SPONSORED CLAIM
+// Same as claim, but a relayer pays the transaction fee and may
+// be refunded from the treasury. The claimer only signs.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SponsoredClaim<'info> {
+    pub claim: Claim<'info>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+}
+
+pub fn handle_sponsored_claim(
+    ctx: Context<SponsoredClaim>,
+    amount: u64,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.claim.faucet_config.sponsored_claims_enabled,
+        AfrodevsError::SponsoredClaimsDisabled
+    );
+
+    // A record created just now by the claim's init_if_needed was
+    // paid for by the claimer, which is what this path avoids.
+    require!(
+        ctx.accounts.claim.claimer_record.wallet == ctx.accounts.claim.claimer.key(),
+        AfrodevsError::SponsoredRecordMissing
+    );
+
+    process_claim(
+        &mut ctx.accounts.claim,
+        &ctx.bumps.claim,
+        ctx.remaining_accounts,
+        amount,
+        referrer,
+    )?;
+
+    // ── RELAYER REFUND ───────────────────────────────────────
+
+    let claim = &mut ctx.accounts.claim;
+    let relayer = &ctx.accounts.relayer;
+    let config = &mut claim.faucet_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let treasury_balance = claim.treasury_vault.lamports();
+    let reimbursement = config
+        .sponsor_reimbursement_for(&relayer.key())
+        .min(config.spendable_balance(treasury_balance));
+
+    if reimbursement > 0 {
+        pay_from_treasury(
+            config,
+            &claim.treasury_vault,
+            &relayer.to_account_info(),
+            &claim.system_program,
+            ctx.bumps.claim.treasury_vault,
+            reimbursement,
+            current_time,
+        )?;
+
+        config.sponsor_reimbursed_today = config.sponsor_reimbursed_today
+            .checked_add(reimbursement)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+
+    emit!(SponsoredClaimEvent {
+        claimer: claim.claimer.key(),
+        relayer: relayer.key(),
+        reimbursement,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 31: CHECK ELIGIBILITY
+// Read-only. Runs the claim gauntlet for a wallet and amount and
+// returns the outcome instead of failing. Meant to be simulated.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct CheckEligibility<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA — balance is read only
+    #[account(
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    /// CHECK: The wallet's ClaimerRecord PDA. Always passed, so a
+    /// wallet with history can't be checked as a new one; holds no
+    /// data until the wallet first claims.
+    #[account(
+        seeds = [CLAIMER_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub claimer_record: AccountInfo<'info>,
+}
+
+pub fn handle_check_eligibility(
+    ctx: Context<CheckEligibility>,
+    _wallet: Pubkey,
+    amount: u64,
+) -> Result<EligibilityResult> {
+    let config = &ctx.accounts.faucet_config;
+    let claimer_record = load_claimer_record(&ctx.accounts.claimer_record)?;
+    let claimer_record = claimer_record.as_ref();
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    let treasury_balance = ctx.accounts.treasury_vault.lamports();
+    let runway_multiplier_bps = config.runway_multiplier_bps(treasury_balance);
+    let tier_index = config.get_tier_index(amount);
+
+    let mut cooldown_remaining = [0i64; COOLDOWN_TIER_COUNT];
+    if let Some(record) = claimer_record {
+        for (i, remaining) in cooldown_remaining.iter_mut().enumerate() {
+            *remaining = record.cooldown_remaining(i, current_time);
+        }
+    }
+
+    let mut result = EligibilityResult {
+        eligible: true,
+        error_code: 0,
+        tier_index: tier_index.map(|i| i as u8),
+        payout: apply_bps(amount, runway_multiplier_bps),
+        runway_multiplier_bps,
+        cooldown_remaining,
+        next_eligible_at: current_time,
+        daily_remaining: apply_bps(config.daily_global_limit, runway_multiplier_bps)
+            .saturating_sub(config.daily_distributed_at(current_time)),
+        treasury_headroom: config.spendable_balance(treasury_balance),
+        tier_shortfall: tier_index
+            .map(|i| config.tier_shortfall(i, claimer_record, current_time))
+            .unwrap_or_default(),
+    };
+
+    if let Err(error) = check_claim(
+        config,
+        claimer_record,
+        treasury_balance,
+        amount,
+        current_time,
+        clock.slot,
+    ) {
+        result.eligible = false;
+        result.error_code = error.into();
+        result.next_eligible_at = match (error, tier_index) {
+            (AfrodevsError::CooldownActive, Some(i)) => current_time + cooldown_remaining[i],
+            (AfrodevsError::DailyLimitReached, _) => config.daily_reset_timestamp + SECONDS_PER_DAY,
+            // Only waiting fixes account age; claims and referrals don't
+            (AfrodevsError::TierLocked, _)
+                if result.tier_shortfall.claims == 0 && result.tier_shortfall.referrals == 0 =>
+            {
+                current_time + result.tier_shortfall.account_age
+            }
+            _ => 0,
+        };
+    }
+
+    Ok(result)
+}
+
+// ============================================================
+// INSTRUCTION 32: SET INTEGRATOR
+// Admin adds or removes a program from the cpi_claim allowlist.
+// ============================================================
+
+pub fn handle_set_integrator(
+    ctx: Context<UpdateConfig>,
+    program_id: Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    require!(program_id != Pubkey::default(), AfrodevsError::InvalidIntegrator);
+
+    let before = config.integrator_programs;
+    if allowed {
+        if !config.is_integrator(&program_id) {
+            let slot = config.integrator_programs
+                .iter_mut()
+                .find(|slot| **slot == Pubkey::default())
+                .ok_or(AfrodevsError::IntegratorListFull)?;
+            *slot = program_id;
+        }
+    } else {
+        for slot in config.integrator_programs.iter_mut() {
+            if *slot == program_id {
+                *slot = Pubkey::default();
+            }
+        }
+    }
+
+    let mut changed = Vec::new();
+    if config.integrator_programs != before {
+        changed.push(String::from("integrator_programs"));
+    }
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 33: CPI CLAIM
+// An allowlisted program claims for one of its PDAs and names
+// where the SOL goes. The PDA is the claimer: cooldowns, blocks
+// and streaks apply to it like any wallet. No referrals.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CpiClaim<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA — sends SOL
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimerRecord::LEN,
+        seeds = [CLAIMER_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_record: Account<'info, ClaimerRecord>,
+
+    /// Required once the claimers leaderboard is initialized.
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, &[LEADERBOARD_KIND_CLAIMERS]],
+        bump = claimers_leaderboard.bump,
+    )]
+    pub claimers_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
+    /// The integrator's PDA, signed for with invoke_signed.
+    pub claimer: Signer<'info>,
+
+    /// CHECK: Any account the integrator chooses to receive the SOL
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+
+    /// Pays rent for a new ClaimerRecord.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar — identifies the calling program
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_cpi_claim(ctx: Context<CpiClaim>, amount: u64) -> Result<CpiClaimResult> {
+    require_leaderboard(
+        &ctx.accounts.faucet_config,
+        LEADERBOARD_KIND_CLAIMERS,
+        ctx.accounts.claimers_leaderboard.is_some(),
+    )?;
+
+    // The caller must be the transaction-level instruction's program,
+    // one CPI deep. Deeper calls can't be attributed reliably.
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+        AfrodevsError::UnauthorizedIntegrator
+    );
+    let integrator = get_instruction_relative(0, &ctx.accounts.instructions)
+        .map_err(|_| AfrodevsError::UnauthorizedIntegrator)?
+        .program_id;
+
+    let accounts = &mut *ctx.accounts;
+    let config = &mut accounts.faucet_config;
+    let claimer_record = &mut accounts.claimer_record;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let current_slot = clock.slot;
+
+    require!(config.is_integrator(&integrator), AfrodevsError::UnauthorizedIntegrator);
+
+    config.roll_daily_counters(current_time);
+
+    let treasury_balance = accounts.treasury_vault.lamports();
+    let check = check_claim(
+        config,
+        Some(claimer_record),
+        treasury_balance,
+        amount,
+        current_time,
+        current_slot,
+    )?;
+
+    let current_streak = check.current_streak;
+    let streak_bonus_applied = check.streak_bonus;
+    let total_amount = check.scaled_amount
+        .checked_add(streak_bonus_applied)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    pay_from_treasury(
+        config,
+        &accounts.treasury_vault,
+        &accounts.destination,
+        &accounts.system_program,
+        ctx.bumps.treasury_vault,
+        total_amount,
+        current_time,
+    )?;
+    check_velocity(config, total_amount, current_time);
+
+    let new_cooldown_end = record_claim(
+        config,
+        claimer_record,
+        accounts.claimer.key(),
+        ctx.bumps.claimer_record,
+        &check,
+        total_amount,
+        current_streak,
+        current_time,
+        current_slot,
+    )?;
+
+    if let Some(board) = accounts.claimers_leaderboard.as_mut() {
+        board.record(accounts.claimer.key(), claimer_record.total_claimed, current_time);
+    }
+
+    emit!(ClaimEvent {
+        claimer: accounts.claimer.key(),
+        amount: total_amount,
+        timestamp: current_time,
+        claimer_total: claimer_record.total_claimed,
+        claimer_claim_count: claimer_record.total_claims,
+        cooldown_ends_at: new_cooldown_end,
+        tier_index: check.tier_index as u8,
+        was_referral: false,
+        referral_bonus_applied: 0,
+        current_streak: claimer_record.current_streak,
+        longest_streak: claimer_record.longest_streak,
+        streak_bonus_applied,
+        base_amount: amount,
+        scaled_amount: check.scaled_amount,
+        runway_multiplier_bps: check.runway_multiplier_bps,
+    });
+
+    emit!(CpiClaimEvent {
+        integrator,
+        claimer: accounts.claimer.key(),
+        destination: accounts.destination.key(),
+        amount: total_amount,
+        timestamp: current_time,
+    });
+
+    Ok(CpiClaimResult {
+        amount_paid: total_amount,
+        next_eligible_at: new_cooldown_end,
+    })
+}
+
+// ============================================================
+// INSTRUCTION 34: SET PAUSED
+// Admin pauses or resumes individual features. See PAUSE_* in
+// constants.rs.
+// ============================================================
+
+pub fn handle_set_paused(
+    ctx: Context<UpdateConfig>,
+    features: u16,
+    paused: bool,
+    reason: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    require!(
+        features != 0 && features & !PAUSE_ALL == 0,
+        AfrodevsError::InvalidPauseFeatures
+    );
+    let reason = match (paused, reason) {
+        (false, _) => PAUSE_REASON_NONE,
+        (true, PAUSE_REASON_NONE) => PAUSE_REASON_MANUAL,
+        (true, reason) => reason,
+    };
+
+    config.set_paused(features, paused, reason);
+
+    emit!(PauseUpdatedEvent {
+        features,
+        paused,
+        reason,
+        paused_features: config.paused_features,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 35: UPDATE CIRCUIT BREAKER
+// Admin sets the claim outflow limit and the window it applies to.
+// ============================================================
+
+pub fn handle_update_circuit_breaker(
+    ctx: Context<UpdateConfig>,
+    limit: Option<u64>,
+    window_minutes: Option<u8>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    if let Some(v) = window_minutes {
+        require!(
+            (1..=OUTFLOW_BUCKET_COUNT as u8).contains(&v),
+            AfrodevsError::InvalidCircuitBreakerWindow
+        );
+    }
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, limit, circuit_breaker_limit);
+    set_field!(config, changed, window_minutes, circuit_breaker_window_minutes);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 36: RESET CIRCUIT BREAKER
+// Admin clears the outflow window and resumes whatever the
+// breaker paused. Pauses with other reasons are left alone.
+// ============================================================
+
+pub fn handle_reset_circuit_breaker(ctx: Context<UpdateConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+
+    config.outflow_buckets = [0u64; OUTFLOW_BUCKET_COUNT];
+
+    let tripped = config.features_paused_for(PAUSE_REASON_CIRCUIT_BREAKER);
+    if tripped != 0 {
+        config.set_paused(tripped, false, PAUSE_REASON_NONE);
+
+        emit!(PauseUpdatedEvent {
+            features: tripped,
+            paused: false,
+            reason: PAUSE_REASON_NONE,
+            paused_features: config.paused_features,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 37: CREATE CAMPAIGN
+// Admin opens a time-bounded faucet with its own tiers and rules,
+// escrowing its budget out of the treasury.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Campaign::LEN,
+        seeds = [CAMPAIGN_SEED, &campaign_id.to_le_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_campaign(
+    ctx: Context<CreateCampaign>,
+    campaign_id: u64,
+    name: String,
+    budget: u64,
+    starts_at: i64,
+    ends_at: i64,
+    tier_amounts: [u64; COOLDOWN_TIER_COUNT],
+    cooldown_seconds: i64,
+    per_wallet_limit: u64,
+    allowlist_root: [u8; 32],
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require!(budget > 0, AfrodevsError::InvalidAmount);
+    require!(name.len() <= MAX_NAME_LENGTH, AfrodevsError::NameTooLong);
+    require!(
+        ends_at > starts_at && ends_at > timestamp && cooldown_seconds >= 0,
+        AfrodevsError::InvalidCampaign
+    );
+    require!(
+        tier_amounts.iter().any(|amount| *amount > 0)
+            && tier_amounts.iter().all(|amount| *amount <= budget),
+        AfrodevsError::InvalidCampaign
+    );
+
+    let treasury_balance = ctx.accounts.treasury_vault.lamports();
+    require!(
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= budget,
+        AfrodevsError::InsufficientTreasury
+    );
+
+    // ── MOVE BUDGET INTO ESCROW ──────────────────────────────
+
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.campaign.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        budget,
+        timestamp,
+    )?;
+
+    // ── WRITE CAMPAIGN ───────────────────────────────────────
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.campaign_id = campaign_id;
+    campaign.authority = ctx.accounts.authority.key();
+    campaign.name = pad_bytes(&name);
+    campaign.budget = budget;
+    campaign.starts_at = starts_at;
+    campaign.ends_at = ends_at;
+    campaign.tier_amounts = tier_amounts;
+    campaign.cooldown_seconds = cooldown_seconds;
+    campaign.per_wallet_limit = per_wallet_limit;
+    campaign.allowlist_root = allowlist_root;
+    campaign.status = CAMPAIGN_STATUS_ACTIVE;
+    campaign.total_claimed = 0;
+    campaign.claim_count = 0;
+    campaign.unique_claimers = 0;
+    campaign.swept_amount = 0;
+    campaign.created_at = timestamp;
+    campaign.closed_at = 0;
+    campaign.bump = ctx.bumps.campaign;
+
+    emit!(CampaignCreatedEvent {
+        campaign: campaign.key(),
+        campaign_id,
+        name,
+        budget,
+        starts_at,
+        ends_at,
+        authority: campaign.authority,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 38: CLAIM CAMPAIGN
+// Claim from a running campaign. Uses the campaign's tiers,
+// cooldown and per-wallet limit instead of the global ones;
+// global claim pauses and wallet blocks still apply.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ClaimCampaign<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = CampaignClaimRecord::LEN,
+        seeds = [CAMPAIGN_CLAIM_SEED, campaign.key().as_ref(), claimer.key().as_ref()],
+        bump
+    )]
+    pub campaign_claim_record: Account<'info, CampaignClaimRecord>,
+
+    /// CHECK: The claimer's ClaimerRecord PDA. May not exist; only
+    /// read to honour wallet blocks.
+    #[account(
+        seeds = [CLAIMER_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_record: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_campaign(
+    ctx: Context<ClaimCampaign>,
+    amount: u64,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let claimer = ctx.accounts.claimer.key();
+    let campaign_info = ctx.accounts.campaign.to_account_info();
+    let campaign = &mut ctx.accounts.campaign;
+    let record = &mut ctx.accounts.campaign_claim_record;
+
+    require!(
+        !ctx.accounts.faucet_config.is_paused(PAUSE_CLAIMS),
+        AfrodevsError::FaucetPaused
+    );
+    require!(campaign.is_open(current_time), AfrodevsError::CampaignNotOpen);
+    require!(campaign.is_tier(amount), AfrodevsError::InvalidAmount);
+    require!(
+        campaign.is_allowlisted(&claimer, &allowlist_proof),
+        AfrodevsError::NotOnCampaignAllowlist
+    );
+
+    require_not_blocked(&ctx.accounts.claimer_record)?;
+
+    let is_new_claimer = record.claim_count == 0;
+    if !is_new_claimer {
+        require!(
+            current_time >= record.last_claim_at.saturating_add(campaign.cooldown_seconds),
+            AfrodevsError::CooldownActive
+        );
+    }
+
+    let wallet_total = record.total_claimed
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    require!(
+        campaign.per_wallet_limit == 0 || wallet_total <= campaign.per_wallet_limit,
+        AfrodevsError::CampaignWalletLimitReached
+    );
+    require!(amount <= campaign.remaining_budget(), AfrodevsError::CampaignBudgetExhausted);
+
+    // ── EXECUTE TRANSFER ─────────────────────────────────────
+
+    release_escrow(&campaign_info, &ctx.accounts.claimer.to_account_info(), amount)?;
+
+    // ── UPDATE STATE ─────────────────────────────────────────
+
+    if is_new_claimer {
+        record.campaign = campaign.key();
+        record.wallet = claimer;
+        record.bump = ctx.bumps.campaign_claim_record;
+        campaign.unique_claimers = campaign.unique_claimers
+            .checked_add(1)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+    record.total_claimed = wallet_total;
+    record.claim_count = record.claim_count
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+    record.last_claim_at = current_time;
+
+    campaign.total_claimed = campaign.total_claimed
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    campaign.claim_count = campaign.claim_count
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    emit!(CampaignClaimEvent {
+        campaign: campaign.key(),
+        claimer,
+        amount,
+        wallet_total,
+        remaining_budget: campaign.remaining_budget(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 39: CLOSE CAMPAIGN
+// Admin ends a campaign early. Claims stop immediately; the
+// budget stays escrowed until swept.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CloseCampaign<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump,
+        constraint = campaign.status == CAMPAIGN_STATUS_ACTIVE @ AfrodevsError::CampaignNotOpen,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.status = CAMPAIGN_STATUS_CLOSED;
+    campaign.closed_at = current_time;
+
+    emit!(CampaignClosedEvent {
+        campaign: campaign.key(),
+        total_claimed: campaign.total_claimed,
+        remaining_budget: campaign.remaining_budget(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 40: SWEEP CAMPAIGN
+// Anyone can return a finished campaign's unspent budget to the
+// treasury. The account stays behind as the campaign's stats
+// until the admin closes it.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SweepCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Treasury vault PDA — receives the unspent budget
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    pub cranker: Signer<'info>,
+}
+
+pub fn handle_sweep_campaign(ctx: Context<SweepCampaign>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let campaign_info = ctx.accounts.campaign.to_account_info();
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.is_sweepable(current_time), AfrodevsError::CampaignNotFinished);
+
+    let amount = campaign.remaining_budget();
+    release_escrow(&campaign_info, &ctx.accounts.treasury_vault, amount)?;
+
+    campaign.status = CAMPAIGN_STATUS_SWEPT;
+    campaign.swept_amount = amount;
+    if campaign.closed_at == 0 {
+        campaign.closed_at = current_time;
+    }
+
+    emit!(CampaignSweptEvent {
+        campaign: campaign.key(),
+        amount,
+        cranked_by: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        new_treasury_balance: ctx.accounts.treasury_vault.lamports(),
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 41: CREATE VOUCHER
+// Admin registers the public key derived from a secret code. Use
+// long random codes: the key is public and short codes can be
+// guessed.
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(code_key: Pubkey)]
+pub struct CreateVoucher<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Voucher::LEN,
+        seeds = [VOUCHER_SEED, code_key.as_ref()],
+        bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_voucher(
+    ctx: Context<CreateVoucher>,
+    code_key: Pubkey,
+    amount: u64,
+    expires_at: i64,
+    max_uses: u32,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, AfrodevsError::InvalidAmount);
+    require!(max_uses > 0 && expires_at > timestamp, AfrodevsError::InvalidVoucher);
+
+    let voucher = &mut ctx.accounts.voucher;
+    voucher.code_key = code_key;
+    voucher.authority = ctx.accounts.authority.key();
+    voucher.amount = amount;
+    voucher.expires_at = expires_at;
+    voucher.max_uses = max_uses;
+    voucher.uses = 0;
+    voucher.total_redeemed = 0;
+    voucher.created_at = timestamp;
+    voucher.bump = ctx.bumps.voucher;
+
+    emit!(VoucherCreatedEvent {
+        voucher: voucher.key(),
+        amount,
+        expires_at,
+        max_uses,
+        authority: voucher.authority,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 42: REDEEM VOUCHER
+// The holder proves they know the code and is paid from the
+// treasury. Must directly follow an Ed25519 program instruction
+// verifying the code key's signature over redemption_message.
+// Once per wallet per voucher.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct RedeemVoucher<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA — sends SOL
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, voucher.code_key.as_ref()],
+        bump = voucher.bump,
+    )]
+    pub voucher: Account<'info, Voucher>,
+
+    #[account(
+        init_if_needed,
+        payer = redeemer,
+        space = VoucherRedemption::LEN,
+        seeds = [VOUCHER_REDEMPTION_SEED, voucher.key().as_ref(), redeemer.key().as_ref()],
+        bump
+    )]
+    pub voucher_redemption: Account<'info, VoucherRedemption>,
+
+    /// CHECK: The redeemer's ClaimerRecord PDA. May not exist; only
+    /// read to honour wallet blocks.
+    #[account(
+        seeds = [CLAIMER_SEED, redeemer.key().as_ref()],
+        bump
+    )]
+    pub claimer_record: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar — holds the code key's signature
+    #[account(address = solana_instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_redeem_voucher(ctx: Context<RedeemVoucher>) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let config = &mut accounts.faucet_config;
+    let voucher = &mut accounts.voucher;
+    let redemption = &mut accounts.voucher_redemption;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!config.is_paused(PAUSE_CLAIMS), AfrodevsError::FaucetPaused);
+    require_ed25519_signature(
+        &accounts.instructions,
+        &voucher.code_key,
+        &Voucher::redemption_message(&voucher.key(), &accounts.redeemer.key()),
+    )?;
+    require!(!voucher.is_expired(current_time), AfrodevsError::VoucherExpired);
+    require!(!voucher.is_used_up(), AfrodevsError::VoucherUsedUp);
+    require!(redemption.redeemed_at == 0, AfrodevsError::VoucherAlreadyRedeemed);
+    require_not_blocked(&accounts.claimer_record)?;
+
+    // Vouchers are scaled by treasury runway and count against the
+    // same daily limit and circuit breaker as claims.
+    config.roll_daily_counters(current_time);
+
+    let treasury_balance = accounts.treasury_vault.lamports();
+    let runway_multiplier_bps = config.runway_multiplier_bps(treasury_balance);
+    let amount = apply_bps(voucher.amount, runway_multiplier_bps);
+    require!(amount > 0, AfrodevsError::InsufficientTreasury);
+    let projected_daily = config.daily_global_distributed
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    require!(
+        projected_daily <= apply_bps(config.daily_global_limit, runway_multiplier_bps),
+        AfrodevsError::DailyLimitReached
+    );
+    require!(
+        config.spendable_balance(treasury_balance) >= amount,
+        AfrodevsError::InsufficientTreasury
+    );
+
+    // ── EXECUTE TRANSFER ─────────────────────────────────────
+
+    pay_from_treasury(
+        config,
+        &accounts.treasury_vault,
+        &accounts.redeemer.to_account_info(),
+        &accounts.system_program,
+        ctx.bumps.treasury_vault,
+        amount,
+        current_time,
+    )?;
+    check_velocity(config, amount, current_time);
+
+    // ── UPDATE STATE ─────────────────────────────────────────
+
+    config.daily_global_distributed = projected_daily;
+    config.total_sol_distributed = config.total_sol_distributed
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    redemption.voucher = voucher.key();
+    redemption.wallet = accounts.redeemer.key();
+    redemption.amount = amount;
+    redemption.redeemed_at = current_time;
+    redemption.bump = ctx.bumps.voucher_redemption;
+
+    voucher.uses = voucher.uses
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+    voucher.total_redeemed = voucher.total_redeemed
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    emit!(VoucherRedeemedEvent {
+        voucher: voucher.key(),
+        wallet: redemption.wallet,
+        amount,
+        uses: voucher.uses,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 43: CLOSE VOUCHER
+// Admin closes a voucher once it has expired or been used up,
+// reclaiming its rent.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CloseVoucher<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [VOUCHER_SEED, voucher.code_key.as_ref()],
+        bump = voucher.bump,
+    )]
+    pub voucher: Account<'info, Voucher>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_voucher(ctx: Context<CloseVoucher>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let voucher = &ctx.accounts.voucher;
+
+    require!(
+        voucher.is_expired(current_time) || voucher.is_used_up(),
+        AfrodevsError::VoucherStillActive
+    );
+
+    emit!(VoucherClosedEvent {
+        voucher: voucher.key(),
+        uses: voucher.uses,
+        total_redeemed: voucher.total_redeemed,
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 44: CREATE BOUNTY
+// Admin posts a task and escrows its reward from the treasury.
+// Bounties are numbered from bounty_nonce.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CreateBounty<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Treasury vault PDA
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Bounty::LEN,
+        seeds = [BOUNTY_SEED, &faucet_config.bounty_nonce.to_le_bytes()],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_bounty(
+    ctx: Context<CreateBounty>,
+    reward: u64,
+    deadline: i64,
+    description_hash: [u8; 32],
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
+    require!(reward > 0, AfrodevsError::InvalidAmount);
+    require!(deadline > timestamp, AfrodevsError::InvalidBountyDeadline);
+
+    let treasury_balance = ctx.accounts.treasury_vault.lamports();
+    require!(
+        ctx.accounts.faucet_config.spendable_balance(treasury_balance) >= reward,
+        AfrodevsError::InsufficientTreasury
+    );
+
+    // ── MOVE REWARD INTO ESCROW ──────────────────────────────
+
+    pay_from_treasury(
+        &mut ctx.accounts.faucet_config,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.bounty.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.bumps.treasury_vault,
+        reward,
+        timestamp,
+    )?;
+
+    let config = &mut ctx.accounts.faucet_config;
+    let bounty_id = config.bounty_nonce;
+    config.bounty_nonce = bounty_id
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    // ── WRITE BOUNTY ─────────────────────────────────────────
+
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.bounty_id = bounty_id;
+    bounty.authority = ctx.accounts.authority.key();
+    bounty.reward = reward;
+    bounty.deadline = deadline;
+    bounty.description_hash = description_hash;
+    bounty.status = BOUNTY_STATUS_OPEN;
+    bounty.submission_count = 0;
+    bounty.winner = Pubkey::default();
+    bounty.created_at = timestamp;
+    bounty.settled_at = 0;
+    bounty.bump = ctx.bumps.bounty;
+
+    emit!(BountyCreatedEvent {
+        bounty: bounty.key(),
+        bounty_id,
+        reward,
+        deadline,
+        description_hash,
+        authority: bounty.authority,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 45: SUBMIT BOUNTY
+// Anyone records their work against an open bounty. Submitting
+// again before the deadline replaces the earlier work hash.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SubmitBounty<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = BountySubmission::LEN,
+        seeds = [BOUNTY_SUBMISSION_SEED, bounty.key().as_ref(), submitter.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, BountySubmission>,
+
+    /// CHECK: The submitter's ClaimerRecord PDA. May not exist; only
+    /// read to honour wallet blocks.
+    #[account(
+        seeds = [CLAIMER_SEED, submitter.key().as_ref()],
+        bump
+    )]
+    pub claimer_record: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_submit_bounty(ctx: Context<SubmitBounty>, work_uri_hash: [u8; 32]) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let bounty = &mut ctx.accounts.bounty;
+    let submission = &mut ctx.accounts.submission;
+
+    require!(bounty.is_accepting_submissions(current_time), AfrodevsError::BountyNotOpen);
+    require_not_blocked(&ctx.accounts.claimer_record)?;
+
+    if submission.submitted_at == 0 {
+        submission.bounty = bounty.key();
+        submission.submitter = ctx.accounts.submitter.key();
+        submission.bump = ctx.bumps.submission;
+        bounty.submission_count = bounty.submission_count
+            .checked_add(1)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+    submission.work_uri_hash = work_uri_hash;
+    submission.submitted_at = current_time;
+
+    emit!(BountySubmittedEvent {
+        bounty: bounty.key(),
+        submitter: submission.submitter,
+        work_uri_hash,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 46: AWARD BOUNTY
+// Admin picks a submission and pays its submitter the reward.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct AwardBounty<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+        constraint = bounty.status == BOUNTY_STATUS_OPEN @ AfrodevsError::BountyNotOpen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        seeds = [BOUNTY_SUBMISSION_SEED, bounty.key().as_ref(), winner.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, BountySubmission>,
+
+    /// CHECK: The winning submitter — must match the submission PDA
+    #[account(mut)]
+    pub winner: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_award_bounty(ctx: Context<AwardBounty>) -> Result<()> {
+    require!(!ctx.accounts.faucet_config.is_paused(PAUSE_GRANTS), AfrodevsError::GrantsPaused);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let bounty_info = ctx.accounts.bounty.to_account_info();
+    let bounty = &mut ctx.accounts.bounty;
+
+    release_escrow(&bounty_info, &ctx.accounts.winner, bounty.reward)?;
+
+    bounty.status = BOUNTY_STATUS_AWARDED;
+    bounty.winner = ctx.accounts.winner.key();
+    bounty.settled_at = current_time;
+
+    emit!(BountyAwardedEvent {
+        bounty: bounty.key(),
+        winner: bounty.winner,
+        reward: bounty.reward,
+        work_uri_hash: ctx.accounts.submission.work_uri_hash,
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 47: EXPIRE BOUNTY
+// Anyone can return an unawarded bounty's reward to the treasury
+// once the deadline and review period have passed.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ExpireBounty<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_SEED, &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Treasury vault PDA — receives the escrowed reward
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: AccountInfo<'info>,
+
+    pub cranker: Signer<'info>,
+}
+
+pub fn handle_expire_bounty(ctx: Context<ExpireBounty>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let bounty_info = ctx.accounts.bounty.to_account_info();
+    let bounty = &mut ctx.accounts.bounty;
+
+    require!(bounty.is_expired(current_time), AfrodevsError::BountyNotExpired);
+
+    release_escrow(&bounty_info, &ctx.accounts.treasury_vault, bounty.reward)?;
+
+    bounty.status = BOUNTY_STATUS_EXPIRED;
+    bounty.settled_at = current_time;
+
+    emit!(BountyExpiredEvent {
+        bounty: bounty.key(),
+        reward: bounty.reward,
+        cranked_by: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        new_treasury_balance: ctx.accounts.treasury_vault.lamports(),
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 48: UPDATE TIER REQUIREMENTS
+// Admin sets what a wallet needs before it can claim a tier.
+// ============================================================
+
+pub fn handle_update_tier_requirements(
+    ctx: Context<UpdateConfig>,
+    tier_index: u8,
+    min_claims: Option<u64>,
+    min_account_age: Option<i64>,
+    min_referrals: Option<u64>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.faucet_config;
+    let clock = Clock::get()?;
+    let tier = tier_index as usize;
+
+    require!(tier < COOLDOWN_TIER_COUNT, AfrodevsError::InvalidTierRequirements);
+
+    if let Some(v) = min_account_age {
+        require!(v >= 0, AfrodevsError::InvalidTierRequirements);
+    }
+
+    let mut changed = Vec::new();
+    set_field!(config, changed, min_claims, tier_min_claims[tier]);
+    set_field!(config, changed, min_account_age, tier_min_account_age[tier]);
+    set_field!(config, changed, min_referrals, tier_min_referrals[tier]);
+
+    emit_config_updated(ctx.accounts.authority.key(), clock.unix_timestamp, changed);
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 49: CLOSE GRANT RECORD
+// Admin recovers the rent of a settled grant. A milestone
+// grant's schedule is closed along with it.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CloseGrantRecord<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        constraint = grant_record.is_settled() @ AfrodevsError::GrantNotSettled,
+    )]
+    pub grant_record: Account<'info, GrantRecord>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [GRANT_MILESTONES_SEED, grant_record.key().as_ref()],
+        bump = grant_milestones.bump,
+    )]
+    pub grant_milestones: Option<Box<Account<'info, GrantMilestones>>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_grant_record(ctx: Context<CloseGrantRecord>) -> Result<()> {
+    let grant = &ctx.accounts.grant_record;
+
+    // The schedule is only reachable through its grant, so it can't
+    // be left behind once the grant is gone.
+    require!(
+        (grant.grant_type == GRANT_TYPE_MILESTONE) == ctx.accounts.grant_milestones.is_some(),
+        AfrodevsError::GrantMilestonesRequired
+    );
+
+    emit!(GrantRecordClosedEvent {
+        grant: grant.key(),
+        recipient: grant.recipient,
+        status: grant.status,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 50: CLOSE CAMPAIGN ACCOUNT
+// Admin recovers the rent of a swept campaign once its stats
+// are no longer needed on chain.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CloseCampaignAccount<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CAMPAIGN_SEED, &campaign.campaign_id.to_le_bytes()],
+        bump = campaign.bump,
+        constraint = campaign.status == CAMPAIGN_STATUS_SWEPT @ AfrodevsError::CampaignNotSwept,
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_campaign_account(ctx: Context<CloseCampaignAccount>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+
+    emit!(CampaignAccountClosedEvent {
+        campaign: campaign.key(),
+        total_claimed: campaign.total_claimed,
+        swept_amount: campaign.swept_amount,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 51: CLOSE BOUNTY
+// Admin recovers the rent of an awarded or expired bounty.
+// Submissions stay with their submitters.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    #[account(
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+        has_one = authority @ AfrodevsError::Unauthorized,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [BOUNTY_SEED, &bounty.bounty_id.to_le_bytes()],
+        bump = bounty.bump,
+        constraint = bounty.status != BOUNTY_STATUS_OPEN @ AfrodevsError::BountyStillOpen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+
+    emit!(BountyClosedEvent {
+        bounty: bounty.key(),
+        bounty_id: bounty.bounty_id,
+        status: bounty.status,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 52: MIGRATE CONFIG
+// Admin rewrites a config left by an older build in the current
+// layout, growing the account and paying the extra rent.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: An older layout won't deserialize as FaucetConfig, so the
+    /// handler checks the discriminator and decodes by length
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub faucet_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.faucet_config.to_account_info();
+    let authority = &ctx.accounts.authority;
+
+    let (from_version, mut config) = {
+        let data = config_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *FaucetConfig::DISCRIMINATOR,
+            AfrodevsError::InvalidAccountLayout
+        );
+        let mut body = &data[8..];
+        match data.len() {
+            FaucetConfigV0::LEN => (0, FaucetConfigV0::deserialize(&mut body)?.upgrade()),
+            FaucetConfig::LEN => {
+                let config = FaucetConfig::deserialize(&mut body)?;
+                (config.layout_version, config)
+            }
+            _ => return err!(AfrodevsError::InvalidAccountLayout),
+        }
+    };
+    require_keys_eq!(config.authority, authority.key(), AfrodevsError::Unauthorized);
+    require!(from_version < CONFIG_LAYOUT_VERSION, AfrodevsError::AccountAlreadyMigrated);
+
+    grow_account(&config_info, authority, &ctx.accounts.system_program, FaucetConfig::LEN)?;
+    config.layout_version = CONFIG_LAYOUT_VERSION;
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit!(ConfigMigratedEvent {
+        authority: authority.key(),
+        from_version,
+        to_version: CONFIG_LAYOUT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// INSTRUCTION 53: MIGRATE RECORD
+// Anyone grows a ClaimerRecord, ReferralRecord or GrantRecord
+// written before fields were appended to it. The new fields get
+// values consistent with what the record already says.
+// ============================================================
+
+#[derive(Accounts)]
+pub struct MigrateRecord<'info> {
+    #[account(
+        mut,
+        seeds = [FAUCET_CONFIG_SEED],
+        bump = faucet_config.bump,
+    )]
+    pub faucet_config: Account<'info, FaucetConfig>,
+
+    /// CHECK: Matched against the record discriminators and their
+    /// old lengths by the handler
+    #[account(mut, owner = crate::ID)]
+    pub record: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_record(ctx: Context<MigrateRecord>) -> Result<()> {
+    let record_info = ctx.accounts.record.to_account_info();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let (discriminator, old_len) = {
+        let data = record_info.try_borrow_data()?;
+        require!(data.len() >= 8, AfrodevsError::InvalidAccountLayout);
+        (data[..8].to_vec(), data.len())
+    };
+    let (v0_len, len) = match discriminator.as_slice() {
+        d if d == ClaimerRecord::DISCRIMINATOR => (ClaimerRecord::V0_LEN, ClaimerRecord::LEN),
+        d if d == ReferralRecord::DISCRIMINATOR => (ReferralRecord::V0_LEN, ReferralRecord::LEN),
+        d if d == GrantRecord::DISCRIMINATOR => (GrantRecord::V0_LEN, GrantRecord::LEN),
+        _ => return err!(AfrodevsError::InvalidAccountLayout),
+    };
+    require!(old_len != len, AfrodevsError::AccountAlreadyMigrated);
+    require!(old_len == v0_len, AfrodevsError::InvalidAccountLayout);
+
+    // The appended fields are all integers, so the grown record
+    // decodes with them zeroed and only the non-zero ones are set
+    grow_account(&record_info, &ctx.accounts.payer, &ctx.accounts.system_program, len)?;
+    let mut data = record_info.try_borrow_mut_data()?;
+    match discriminator.as_slice() {
+        d if d == ClaimerRecord::DISCRIMINATOR => {
+            let mut record = ClaimerRecord::try_deserialize(&mut &data[..])?;
+            if record.pending_referral_bonus > 0 {
+                // Untracked until now, so the expiry clock starts here
+                record.pending_bonus_since = current_time;
+                let config = &mut ctx.accounts.faucet_config;
+                config.outstanding_referral_bonuses = config.outstanding_referral_bonuses
+                    .checked_add(record.pending_referral_bonus)
+                    .ok_or(AfrodevsError::Overflow)?;
+            }
+            if record.total_claims > 0 {
+                record.last_claim_day = DailyStats::day_number(record.last_claim_timestamp);
+                record.current_streak = 1;
+                record.longest_streak = 1;
+            }
+            record.try_serialize(&mut &mut data[..])?;
+        }
+        d if d == ReferralRecord::DISCRIMINATOR => {
+            let mut record = ReferralRecord::try_deserialize(&mut &data[..])?;
+            record.created_at = record.confirmed_at;
+            record.try_serialize(&mut &mut data[..])?;
+        }
+        _ => {
+            let mut record = GrantRecord::try_deserialize(&mut &data[..])?;
+            record.status = GRANT_STATUS_PAID;
+            record.settled_at = record.timestamp;
+            record.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
+    emit!(RecordMigratedEvent {
+        record: record_info.key(),
+        old_len: old_len as u32,
+        new_len: len as u32,
+        payer: ctx.accounts.payer.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+// One ConfigUpdatedEvent per config instruction, naming every field
+// that moved. Nothing is emitted when nothing changed.
+fn emit_config_updated(authority: Pubkey, timestamp: i64, changed: Vec<String>) {
+    if changed.is_empty() {
+        return;
+    }
+    emit!(ConfigUpdatedEvent {
+        authority,
+        timestamp,
+        field_changed: changed.join(","),
+    });
+}
+
+// What the claim gauntlet works out on the way through.
+#[derive(Clone, Copy)]
+struct ClaimCheck {
+    tier_index: usize,
+    runway_multiplier_bps: u16,
+    scaled_amount: u64,
+    current_streak: u64,
+    streak_bonus: u64,
+    daily_limit: u64,
+    spendable: u64,
+}
+
+// Grows a program-owned account to `new_len`, with `payer`
+// topping it up to the new rent-exempt minimum.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let top_up = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
+
+// Books a paid claim on the config and the claimer's record.
+// Returns when the claimed tier's cooldown ends.
+fn record_claim(
+    config: &mut FaucetConfig,
+    claimer_record: &mut ClaimerRecord,
+    claimer: Pubkey,
+    record_bump: u8,
+    check: &ClaimCheck,
+    total_amount: u64,
+    current_streak: u64,
+    current_time: i64,
+    current_slot: u64,
+) -> Result<i64> {
+    let new_cooldown_end = current_time + config.cooldown_tier_seconds[check.tier_index];
+
+    if claimer_record.total_claims == 0 {
+        claimer_record.wallet = claimer;
+        claimer_record.created_at = current_time;
+        claimer_record.cooldown_ends_at = [0i64; 4];
+        claimer_record.bump = record_bump;
+        config.total_unique_claimers = config.total_unique_claimers
+            .checked_add(1)
+            .ok_or(AfrodevsError::Overflow)?;
+    }
+
+    claimer_record.total_claimed = claimer_record.total_claimed
+        .checked_add(total_amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    claimer_record.total_claims = claimer_record.total_claims
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+    claimer_record.last_claim_timestamp = current_time;
+    claimer_record.last_claim_amount = total_amount;
+    claimer_record.cooldown_ends_at[check.tier_index] = new_cooldown_end;
+    claimer_record.last_claim_slot = current_slot;
+    claimer_record.current_streak = current_streak;
+    claimer_record.longest_streak = claimer_record.longest_streak.max(current_streak);
+    claimer_record.last_claim_day = DailyStats::day_number(current_time);
+
+    config.total_sol_distributed = config.total_sol_distributed
+        .checked_add(total_amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    config.total_claims = config.total_claims
+        .checked_add(1)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    // Everything paid counts toward the day, bonuses included. The
+    // gauntlet already checked the payout and streak bonus; this
+    // catches a referral bonus that would tip the day over.
+    let projected_daily = config.daily_global_distributed
+        .checked_add(total_amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    require!(projected_daily <= check.daily_limit, AfrodevsError::DailyLimitReached);
+    config.daily_global_distributed = projected_daily;
+
+    Ok(new_cooldown_end)
+}
+
+// Sends lamports out of the system-owned treasury vault, then runs
+// the low-treasury check so no debit can skip the alert.
+fn pay_from_treasury<'info>(
+    config: &mut FaucetConfig,
+    treasury_vault: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    treasury_bump: u8,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let balance_before = treasury_vault.lamports();
+    let treasury_seeds: &[&[u8]] = &[TREASURY_VAULT_SEED, &[treasury_bump]];
+    let signer_seeds = &[treasury_seeds];
+
+    let cpi_context = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: treasury_vault.clone(),
+            to: destination.clone(),
+        },
+        signer_seeds,
+    );
+    system_program::transfer(cpi_context, amount)?;
+
+    check_low_treasury(config, balance_before, treasury_vault.lamports(), current_time);
+    Ok(())
+}
+
+fn ensure(condition: bool, error: AfrodevsError) -> std::result::Result<(), AfrodevsError> {
+    if condition { Ok(()) } else { Err(error) }
+}
+
+// The claim validation gauntlet, shared by claim and the read-only
+// check_eligibility. Never writes, so the daily counter is read as
+// it would be after a pending reset. `claimer_record` is None for a
+// wallet that has never claimed.
+fn check_claim(
+    config: &FaucetConfig,
+    claimer_record: Option<&ClaimerRecord>,
+    treasury_balance: u64,
+    amount: u64,
+    current_time: i64,
+    current_slot: u64,
+) -> std::result::Result<ClaimCheck, AfrodevsError> {
+    // 1. Claims not paused
+    ensure(!config.is_paused(PAUSE_CLAIMS), AfrodevsError::FaucetPaused)?;
+
+    // 2. Amount matches a valid tier that is not paused
+    let tier_index = config.get_tier_index(amount)
+        .ok_or(AfrodevsError::InvalidAmount)?;
+    ensure(
+        !config.is_paused(FaucetConfig::tier_pause_flag(tier_index)),
+        AfrodevsError::TierPaused,
+    )?;
+
+    // 3. Wallet has unlocked the tier
+    let shortfall = config.tier_shortfall(tier_index, claimer_record, current_time);
+    if !shortfall.is_met() {
+        msg!(
+            "Tier {} locked: needs {} more claims, {}s more account age, {} more referrals",
+            tier_index,
+            shortfall.claims,
+            shortfall.account_age,
+            shortfall.referrals,
+        );
+        return Err(AfrodevsError::TierLocked);
+    }
+
+    // 4. Amount within min/max
+    ensure(amount >= config.min_amount, AfrodevsError::AmountTooLow)?;
+    ensure(amount <= config.max_amount, AfrodevsError::AmountTooHigh)?;
+
+    // 5. Scale the payout and daily limit by treasury runway
+    let runway_multiplier_bps = config.runway_multiplier_bps(treasury_balance);
+    let scaled_amount = apply_bps(amount, runway_multiplier_bps);
+    ensure(scaled_amount > 0, AfrodevsError::InsufficientTreasury)?;
+
+    // 6. Daily global limit not exceeded, streak bonus included
+    let current_streak = claimer_record
+        .map_or(1, |record| record.next_streak(DailyStats::day_number(current_time)));
+    let streak_bonus = config.streak_bonus(scaled_amount, current_streak);
+    let payout = scaled_amount
+        .checked_add(streak_bonus)
+        .ok_or(AfrodevsError::Overflow)?;
+    let daily_limit = apply_bps(config.daily_global_limit, runway_multiplier_bps);
+    let projected_daily = config.daily_distributed_at(current_time)
+        .checked_add(payout)
+        .ok_or(AfrodevsError::Overflow)?;
+    ensure(projected_daily <= daily_limit, AfrodevsError::DailyLimitReached)?;
+
+    // 7. Treasury has enough (keeping rent reserve and owed bonuses)
+    let spendable = config.spendable_balance(treasury_balance);
+    ensure(spendable >= payout, AfrodevsError::InsufficientTreasury)?;
+
+    if let Some(record) = claimer_record {
+        // 8. Wallet not blocked (only if record already existed)
+        // 9. Cooldown for this tier has expired
+        if record.total_claims > 0 {
+            ensure(!record.is_blocked, AfrodevsError::WalletBlocked)?;
+            ensure(
+                !record.is_cooldown_active(tier_index, current_time),
+                AfrodevsError::CooldownActive,
+            )?;
+        }
+
+        // 10. Not same slot as last claim (double-spend prevention)
+        ensure(current_slot > record.last_claim_slot, AfrodevsError::CooldownActive)?;
+    }
+
+    Ok(ClaimCheck {
+        tier_index,
+        runway_multiplier_bps,
+        scaled_amount,
+        current_streak,
+        streak_bonus,
+        daily_limit,
+        spendable,
+    })
+}
+
+// Walks up the referral chain from the direct referrer and
+// queues a decaying share of the referrer bonus for each
+// ancestor, until referral_ancestor_levels is reached, the chain
+// ends or the share decays to zero. Clients pass the ancestors'
+// ClaimerRecords nearest first; a missing, mismatched or repeated
+// ancestor fails the claim. Blocked ancestors earn nothing but
+// the walk carries on past them. Returns the total queued.
+fn credit_referral_ancestors(
+    config: &mut FaucetConfig,
+    direct_referrer: &ClaimerRecord,
+    referrer_bonus: u64,
+    referred: Pubkey,
+    ancestors: &[AccountInfo],
+    current_time: i64,
+) -> Result<u64> {
+    let max_levels = config.referral_ancestor_levels as usize;
+    let mut visited = vec![referred, direct_referrer.wallet];
+    let mut expected = direct_referrer.referred_by;
+    let mut share = config.ancestor_share(referrer_bonus, 0);
+    let mut total_queued: u64 = 0;
+
+    for offset in 0..max_levels {
+        let Some(ancestor_key) = expected else {
+            break;
+        };
+        if offset > 0 {
+            share = config.ancestor_share(share, offset);
+        }
+        if share == 0 {
+            break;
+        }
+
+        let ancestor_info = ancestors.get(offset).ok_or(AfrodevsError::InvalidReferral)?;
+        require!(!visited.contains(&ancestor_key), AfrodevsError::ReferralCycle);
+        require!(ancestor_info.owner == &crate::ID, AfrodevsError::InvalidReferral);
+        require!(ancestor_info.is_writable, AfrodevsError::InvalidReferral);
+
+        let mut ancestor = ClaimerRecord::try_deserialize(&mut &ancestor_info.data.borrow()[..])?;
+        require!(ancestor.wallet == ancestor_key, AfrodevsError::InvalidReferral);
+        visited.push(ancestor_key);
+        expected = ancestor.referred_by;
+
+        if ancestor.is_blocked {
+            continue;
+        }
+
+        queue_referral_bonus(config, &mut ancestor, share, referred, current_time)?;
+        total_queued = total_queued
+            .checked_add(share)
+            .ok_or(AfrodevsError::Overflow)?;
+        ancestor.try_serialize(&mut &mut ancestor_info.data.borrow_mut()[..])?;
+
+        emit!(AncestorReferralBonusEvent {
+            ancestor: ancestor_key,
+            referred,
+            level: (offset + 2) as u8,
+            amount: share,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(total_queued)
+}
+
+// Queues a referral bonus. A pending balance that has already
+// expired is forfeited first, as the crank would have done, so the
+// new bonus starts its own clock. `cranked_by` is the wallet whose
+// claim queued it.
+fn queue_referral_bonus(
+    config: &mut FaucetConfig,
+    record: &mut ClaimerRecord,
+    amount: u64,
+    cranked_by: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    if config.is_referral_bonus_expired(record, current_time) {
+        let expired_amount = record.pending_referral_bonus;
+        record.pending_referral_bonus = 0;
+        record.pending_bonus_since = 0;
+        config.outstanding_referral_bonuses = config.outstanding_referral_bonuses
+            .saturating_sub(expired_amount);
+
+        emit!(ReferralBonusExpiredEvent {
+            referrer: record.wallet,
+            amount: expired_amount,
+            cranked_by,
+            timestamp: current_time,
+        });
+    }
+    record.queue_referral_bonus(amount, current_time)
+}
+
+// Alerts when an outflow takes the vault below the low-water
+// mark and, if enabled, pauses the faucet until it is refilled.
+// Only fires on the crossing so an admin unpause sticks.
+fn check_low_treasury(
+    config: &mut FaucetConfig,
+    balance_before: u64,
+    balance_after: u64,
+    current_time: i64,
+) {
+    if balance_before < config.low_water_mark || balance_after >= config.low_water_mark {
+        return;
+    }
+
+    // Only switch off what isn't already paused, so an admin's own
+    // pause keeps its reason and isn't lifted on recovery.
+    let to_pause = PAUSE_ON_LOW_TREASURY & !config.paused_features;
+    let auto_paused = config.auto_pause_on_low && to_pause != 0;
+    if auto_paused {
+        config.set_paused(to_pause, true, PAUSE_REASON_LOW_TREASURY);
+    }
+
+    emit!(TreasuryLowEvent {
+        balance: balance_after,
+        low_water_mark: config.low_water_mark,
+        auto_paused,
+        pause_reason: config.pause_reason(PAUSE_CLAIMS),
+        timestamp: current_time,
+    });
+}
+
+// Fails unless the instruction just before this one is an Ed25519
+// program sigverify of `message` by `signer`, with everything
+// inline. The runtime has already checked the signature itself.
+fn require_ed25519_signature(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let sigverify = get_instruction_relative(-1, instructions)
+        .map_err(|_| AfrodevsError::InvalidVoucherCode)?;
+    require!(sigverify.program_id == ed25519_program::ID, AfrodevsError::InvalidVoucherCode);
+
+    // [count, padding, then per signature: signature offset and
+    // instruction index, pubkey offset and index, message offset,
+    // size and index], all u16 little-endian.
+    let data = &sigverify.data;
+    require!(data.len() >= 16 && data[0] == 1, AfrodevsError::InvalidVoucherCode);
+    let field = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    require!(
+        field(4) == u16::MAX && field(8) == u16::MAX && field(14) == u16::MAX,
+        AfrodevsError::InvalidVoucherCode
+    );
+
+    let pubkey_start = field(6) as usize;
+    let message_start = field(10) as usize;
+    let message_end = message_start + field(12) as usize;
+    require!(
+        data.get(pubkey_start..pubkey_start + 32) == Some(signer.as_ref())
+            && data.get(message_start..message_end) == Some(message),
+        AfrodevsError::InvalidVoucherCode
+    );
+    Ok(())
+}
+
+// Fails if a leaderboard has been initialized but the caller
+// left it out, which would let the board silently go stale.
+fn require_leaderboard(config: &FaucetConfig, kind: u8, is_passed: bool) -> Result<()> {
+    require!(!config.has_leaderboard(kind) || is_passed, AfrodevsError::LeaderboardRequired);
+    Ok(())
+}
+
+// Reads a ClaimerRecord PDA that may not exist yet. None means the
+// wallet has never claimed.
+fn load_claimer_record(claimer_record: &AccountInfo) -> Result<Option<ClaimerRecord>> {
+    if claimer_record.owner != &crate::ID || claimer_record.data_is_empty() {
+        return Ok(None);
+    }
+    let record = ClaimerRecord::try_deserialize(&mut &claimer_record.try_borrow_data()?[..])?;
+    Ok(Some(record))
+}
+
+// Fails if a ClaimerRecord PDA exists and is blocked. For entry
+// points that don't otherwise need the wallet's record.
+fn require_not_blocked(claimer_record: &AccountInfo) -> Result<()> {
+    if let Some(record) = load_claimer_record(claimer_record)? {
+        require!(!record.is_blocked, AfrodevsError::WalletBlocked);
+    }
+    Ok(())
+}
+
+// Counts a claim payout against the circuit breaker and pauses
+// claims once the window's outflow goes over the limit. The claim
+// that trips it still goes through, since failing it would roll
+// the pause back. Only the authority can reset it.
+fn check_velocity(config: &mut FaucetConfig, amount: u64, current_time: i64) {
+    let window_outflow = config.record_outflow(amount, current_time);
+
+    if config.circuit_breaker_limit == 0
+        || window_outflow <= config.circuit_breaker_limit
+        || config.is_paused(PAUSE_CLAIMS)
+    {
+        return;
+    }
+
+    config.set_paused(PAUSE_CLAIMS, true, PAUSE_REASON_CIRCUIT_BREAKER);
+
+    emit!(CircuitBreakerTrippedEvent {
+        window_outflow,
+        limit: config.circuit_breaker_limit,
+        window_minutes: config.circuit_breaker_window_minutes,
+        timestamp: current_time,
+    });
+}
+
+// Moves lamports out of a program-owned escrow account. The
+// system program cannot debit accounts that carry data, so the
+// balances are adjusted directly.
+fn release_escrow<'info>(
+    escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let escrow_balance = escrow.lamports()
+        .checked_sub(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+    let destination_balance = destination.lamports()
+        .checked_add(amount)
+        .ok_or(AfrodevsError::Overflow)?;
+
+    **escrow.try_borrow_mut_lamports()? = escrow_balance;
+    **destination.try_borrow_mut_lamports()? = destination_balance;
+
+    Ok(())
+}
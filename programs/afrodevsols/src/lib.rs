@@ -1,5 +1,8 @@
 // programs/afrodevsols/src/lib.rs
 
+// Anchor instruction handlers take their arguments positionally.
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 pub mod constants;
@@ -26,6 +29,179 @@ pub struct ClaimEvent {
     pub tier_index: u8,
     pub was_referral: bool,
     pub referral_bonus_applied: u64,
+    pub current_streak: u64,
+    pub longest_streak: u64,
+    pub streak_bonus_applied: u64,
+    pub base_amount: u64,
+    pub scaled_amount: u64,
+    pub runway_multiplier_bps: u16,
+}
+
+#[event]
+pub struct SponsoredClaimEvent {
+    pub claimer: Pubkey,
+    pub relayer: Pubkey,
+    pub reimbursement: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CpiClaimEvent {
+    pub integrator: Pubkey,
+    pub claimer: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignCreatedEvent {
+    pub campaign: Pubkey,
+    pub campaign_id: u64,
+    pub name: String,
+    pub budget: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignClaimEvent {
+    pub campaign: Pubkey,
+    pub claimer: Pubkey,
+    pub amount: u64,
+    pub wallet_total: u64,
+    pub remaining_budget: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignClosedEvent {
+    pub campaign: Pubkey,
+    pub total_claimed: u64,
+    pub remaining_budget: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignSweptEvent {
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub cranked_by: Pubkey,
+    pub timestamp: i64,
+    pub new_treasury_balance: u64,
+}
+
+#[event]
+pub struct CampaignAccountClosedEvent {
+    pub campaign: Pubkey,
+    pub total_claimed: u64,
+    pub swept_amount: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherCreatedEvent {
+    pub voucher: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub max_uses: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherRedeemedEvent {
+    pub voucher: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub uses: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherClosedEvent {
+    pub voucher: Pubkey,
+    pub uses: u32,
+    pub total_redeemed: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyCreatedEvent {
+    pub bounty: Pubkey,
+    pub bounty_id: u64,
+    pub reward: u64,
+    pub deadline: i64,
+    pub description_hash: [u8; 32],
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountySubmittedEvent {
+    pub bounty: Pubkey,
+    pub submitter: Pubkey,
+    pub work_uri_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyAwardedEvent {
+    pub bounty: Pubkey,
+    pub winner: Pubkey,
+    pub reward: u64,
+    pub work_uri_hash: [u8; 32],
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyExpiredEvent {
+    pub bounty: Pubkey,
+    pub reward: u64,
+    pub cranked_by: Pubkey,
+    pub timestamp: i64,
+    pub new_treasury_balance: u64,
+}
+
+#[event]
+pub struct BountyClosedEvent {
+    pub bounty: Pubkey,
+    pub bounty_id: u64,
+    pub status: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigMigratedEvent {
+    pub authority: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecordMigratedEvent {
+    pub record: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralPendingEvent {
+    pub referrer: Pubkey,
+    pub referred: Pubkey,
+    pub timestamp: i64,
+    pub claims_required: u64,
+    pub unlocks_after: i64,
 }
 
 #[event]
@@ -37,6 +213,15 @@ pub struct ReferralConfirmedEvent {
     pub bonus_applied_to_referred: u64,
 }
 
+#[event]
+pub struct AncestorReferralBonusEvent {
+    pub ancestor: Pubkey,
+    pub referred: Pubkey,
+    pub level: u8,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ReferralBonusClaimedEvent {
     pub referrer: Pubkey,
@@ -44,6 +229,36 @@ pub struct ReferralBonusClaimedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ReferralCodeRegisteredEvent {
+    pub code: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralCodeTransferredEvent {
+    pub code: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralCodeReleasedEvent {
+    pub code: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBonusExpiredEvent {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub cranked_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SpecialGrantEvent {
     pub recipient: Pubkey,
@@ -55,6 +270,78 @@ pub struct SpecialGrantEvent {
     pub new_treasury_balance: u64,
 }
 
+#[event]
+pub struct GrantEscrowedEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub is_public: bool,
+    pub authority: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GrantAcceptedEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GrantExpiredEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub cranked_by: Pubkey,
+    pub timestamp: i64,
+    pub new_treasury_balance: u64,
+}
+
+#[event]
+pub struct MilestoneGrantCreatedEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub milestone_count: u8,
+    pub reason: String,
+    pub is_public: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneReleasedEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub milestone_index: u8,
+    pub amount: u64,
+    pub description_hash: [u8; 32],
+    pub released_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneCancelledEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub milestone_index: u8,
+    pub amount: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GrantRecordClosedEvent {
+    pub grant: Pubkey,
+    pub recipient: Pubkey,
+    pub status: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct BulkGrantEvent {
     pub recipients: Vec<Pubkey>,
@@ -80,6 +367,15 @@ pub struct TreasuryFundedEvent {
     pub amount: u64,
     pub new_balance: u64,
     pub timestamp: i64,
+    pub funder_total_donated: u64,
+    pub funder_donation_count: u64,
+}
+
+#[event]
+pub struct FunderNameUpdatedEvent {
+    pub funder: Pubkey,
+    pub display_name: String,
+    pub timestamp: i64,
 }
 
 #[event]
@@ -91,6 +387,41 @@ pub struct WithdrawalEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TreasuryLowEvent {
+    pub balance: u64,
+    pub low_water_mark: u64,
+    pub auto_paused: bool,
+    pub pause_reason: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdatedEvent {
+    pub features: u16,
+    pub paused: bool,
+    pub reason: u8,
+    pub paused_features: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTrippedEvent {
+    pub window_outflow: u64,
+    pub limit: u64,
+    pub window_minutes: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryRecoveredEvent {
+    pub balance: u64,
+    pub high_water_mark: u64,
+    pub funder: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct WalletBlockedEvent {
     pub target_wallet: Pubkey,
@@ -133,7 +464,16 @@ pub mod afrodevsols {
         handle_fund_treasury(ctx, amount)
     }
 
+    /// Funder sets or clears the public name on their FunderRecord.
+    pub fn set_funder_display_name(
+        ctx: Context<SetFunderDisplayName>,
+        display_name: String,
+    ) -> Result<()> {
+        handle_set_funder_display_name(ctx, display_name)
+    }
+
     /// Core claim instruction with full validation.
+    /// Remaining accounts: referral ancestors' ClaimerRecords, nearest first.
     pub fn claim(
         ctx: Context<Claim>,
         amount: u64,
@@ -142,12 +482,91 @@ pub mod afrodevsols {
         handle_claim(ctx, amount, referrer)
     }
 
+    /// Read-only claim check for simulation. Returns an EligibilityResult.
+    pub fn check_eligibility(
+        ctx: Context<CheckEligibility>,
+        wallet: Pubkey,
+        amount: u64,
+    ) -> Result<state::EligibilityResult> {
+        handle_check_eligibility(ctx, wallet, amount)
+    }
+
+    /// Relayer pays rent for a new claimer's records so they can claim with no SOL.
+    pub fn sponsor_claimer_record(ctx: Context<SponsorClaimerRecord>) -> Result<()> {
+        handle_sponsor_claimer_record(ctx)
+    }
+
+    /// Claim with a relayer as fee payer. The relayer may be refunded from the treasury.
+    /// Remaining accounts: referral ancestors' ClaimerRecords, nearest first.
+    pub fn sponsored_claim(
+        ctx: Context<SponsoredClaim>,
+        amount: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        handle_sponsored_claim(ctx, amount, referrer)
+    }
+
+    /// Claim on behalf of an integrator program's PDA, paying out to any destination.
+    /// Only callable through CPI from an allowlisted program. Returns a CpiClaimResult.
+    pub fn cpi_claim(ctx: Context<CpiClaim>, amount: u64) -> Result<state::CpiClaimResult> {
+        handle_cpi_claim(ctx, amount)
+    }
+
+    /// Claim from a running campaign. Pass a Merkle proof if the campaign has an allowlist.
+    pub fn claim_campaign(
+        ctx: Context<ClaimCampaign>,
+        amount: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_campaign(ctx, amount, allowlist_proof)
+    }
+
+    /// Redeem a voucher with a signature by its code's key. Once per wallet per voucher.
+    pub fn redeem_voucher(ctx: Context<RedeemVoucher>) -> Result<()> {
+        handle_redeem_voucher(ctx)
+    }
+
+    /// Claim using a registered referral code instead of a raw pubkey.
+    pub fn claim_with_code(
+        ctx: Context<ClaimWithCode>,
+        amount: u64,
+        code: String,
+    ) -> Result<()> {
+        handle_claim_with_code(ctx, amount, code)
+    }
+
+    /// Reserve a short referral code for the signing wallet.
+    pub fn register_referral_code(
+        ctx: Context<RegisterReferralCode>,
+        code: String,
+    ) -> Result<()> {
+        handle_register_referral_code(ctx, code)
+    }
+
+    /// Hand a referral code over to another wallet.
+    pub fn transfer_referral_code(
+        ctx: Context<TransferReferralCode>,
+        code: String,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        handle_transfer_referral_code(ctx, code, new_owner)
+    }
+
+    /// Give up a referral code and reclaim its rent.
+    pub fn release_referral_code(
+        ctx: Context<ReleaseReferralCode>,
+        code: String,
+    ) -> Result<()> {
+        handle_release_referral_code(ctx, code)
+    }
+
     /// Referrer collects accumulated referral bonuses.
     pub fn claim_referral_bonus(ctx: Context<ClaimReferralBonus>) -> Result<()> {
         handle_claim_referral_bonus(ctx)
     }
 
-    /// Admin sends any amount to one wallet. Bypasses all rules.
+    /// Admin sends SOL to one wallet, skipping claim rules. Refused while grants are
+    /// paused or when the amount exceeds the treasury's balance after liabilities.
     pub fn special_grant(
         ctx: Context<SpecialGrant>,
         recipient: Pubkey,
@@ -158,10 +577,166 @@ pub mod afrodevsols {
         handle_special_grant(ctx, recipient, amount, reason, is_public)
     }
 
+    /// Admin escrows a grant that the recipient must accept before expiry.
+    pub fn escrow_grant(
+        ctx: Context<EscrowGrant>,
+        recipient: Pubkey,
+        amount: u64,
+        reason: String,
+        is_public: bool,
+        expires_in: i64,
+    ) -> Result<()> {
+        handle_escrow_grant(ctx, recipient, amount, reason, is_public, expires_in)
+    }
+
+    /// Recipient pulls an escrowed grant.
+    pub fn accept_grant(ctx: Context<AcceptGrant>) -> Result<()> {
+        handle_accept_grant(ctx)
+    }
+
+    /// Anyone returns an expired escrowed grant to the treasury.
+    pub fn expire_grant(ctx: Context<ExpireGrant>) -> Result<()> {
+        handle_expire_grant(ctx)
+    }
+
+    /// Admin escrows a grant paid out in milestone tranches.
+    pub fn create_milestone_grant(
+        ctx: Context<CreateMilestoneGrant>,
+        recipient: Pubkey,
+        reason: String,
+        is_public: bool,
+        milestone_amounts: Vec<u64>,
+        description_hashes: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_create_milestone_grant(
+            ctx,
+            recipient,
+            reason,
+            is_public,
+            milestone_amounts,
+            description_hashes,
+        )
+    }
+
+    /// Admin releases one milestone to the grant recipient.
+    pub fn approve_milestone(
+        ctx: Context<ApproveMilestone>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        handle_approve_milestone(ctx, milestone_index)
+    }
+
+    /// Admin cancels all unreleased milestones back into the treasury.
+    pub fn cancel_milestone_grant(ctx: Context<CancelMilestoneGrant>) -> Result<()> {
+        handle_cancel_milestone_grant(ctx)
+    }
+
+    /// Admin closes a settled grant record to recover its rent.
+    pub fn close_grant_record(ctx: Context<CloseGrantRecord>) -> Result<()> {
+        handle_close_grant_record(ctx)
+    }
+
+    /// Admin opens a time-bounded campaign, escrowing its budget from the treasury.
+    pub fn create_campaign(
+        ctx: Context<CreateCampaign>,
+        campaign_id: u64,
+        name: String,
+        budget: u64,
+        starts_at: i64,
+        ends_at: i64,
+        tier_amounts: [u64; 4],
+        cooldown_seconds: i64,
+        per_wallet_limit: u64,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        handle_create_campaign(
+            ctx,
+            campaign_id,
+            name,
+            budget,
+            starts_at,
+            ends_at,
+            tier_amounts,
+            cooldown_seconds,
+            per_wallet_limit,
+            allowlist_root,
+        )
+    }
+
+    /// Admin ends a campaign early.
+    pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
+        handle_close_campaign(ctx)
+    }
+
+    /// Anyone returns a finished campaign's unspent budget to the treasury.
+    pub fn sweep_campaign(ctx: Context<SweepCampaign>) -> Result<()> {
+        handle_sweep_campaign(ctx)
+    }
+
+    /// Admin closes a swept campaign to recover its rent.
+    pub fn close_campaign_account(ctx: Context<CloseCampaignAccount>) -> Result<()> {
+        handle_close_campaign_account(ctx)
+    }
+
+    /// Admin creates a voucher keyed by the public key derived from its secret code.
+    pub fn create_voucher(
+        ctx: Context<CreateVoucher>,
+        code_key: Pubkey,
+        amount: u64,
+        expires_at: i64,
+        max_uses: u32,
+    ) -> Result<()> {
+        handle_create_voucher(ctx, code_key, amount, expires_at, max_uses)
+    }
+
+    /// Admin closes an expired or used-up voucher.
+    pub fn close_voucher(ctx: Context<CloseVoucher>) -> Result<()> {
+        handle_close_voucher(ctx)
+    }
+
+    /// Admin posts a bounty, escrowing its reward from the treasury.
+    pub fn create_bounty(
+        ctx: Context<CreateBounty>,
+        reward: u64,
+        deadline: i64,
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        handle_create_bounty(ctx, reward, deadline, description_hash)
+    }
+
+    /// Submit or revise work for an open bounty.
+    pub fn submit_bounty(ctx: Context<SubmitBounty>, work_uri_hash: [u8; 32]) -> Result<()> {
+        handle_submit_bounty(ctx, work_uri_hash)
+    }
+
+    /// Admin pays a bounty's reward to a submitter.
+    pub fn award_bounty(ctx: Context<AwardBounty>) -> Result<()> {
+        handle_award_bounty(ctx)
+    }
+
+    /// Anyone returns an unawarded bounty's reward to the treasury after review closes.
+    pub fn expire_bounty(ctx: Context<ExpireBounty>) -> Result<()> {
+        handle_expire_bounty(ctx)
+    }
+
+    /// Admin closes an awarded or expired bounty to recover its rent.
+    pub fn close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+        handle_close_bounty(ctx)
+    }
+
+    /// Admin rewrites a config from an older build in the current layout.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        handle_migrate_config(ctx)
+    }
+
+    /// Anyone grows a claimer, referral or grant record from an older build.
+    pub fn migrate_record(ctx: Context<MigrateRecord>) -> Result<()> {
+        handle_migrate_record(ctx)
+    }
+
     /// Admin updates any config field. All fields optional.
     pub fn update_config(
         ctx: Context<UpdateConfig>,
-        is_paused: Option<bool>,
         min_amount: Option<u64>,
         max_amount: Option<u64>,
         cooldown_tier_amounts: Option<[u64; 4]>,
@@ -174,7 +749,6 @@ pub mod afrodevsols {
     ) -> Result<()> {
         handle_update_config(
             ctx,
-            is_paused,
             min_amount,
             max_amount,
             cooldown_tier_amounts,
@@ -187,6 +761,153 @@ pub mod afrodevsols {
         )
     }
 
+    /// Admin sets referrer eligibility and the referral unlock thresholds.
+    pub fn update_referral_config(
+        ctx: Context<UpdateConfig>,
+        referral_min_claims: Option<u64>,
+        referral_min_days: Option<u64>,
+        referrer_min_claims: Option<u64>,
+        referral_ancestor_levels: Option<u8>,
+        referral_ancestor_bps: Option<u16>,
+        referral_decay_bps: Option<u16>,
+    ) -> Result<()> {
+        handle_update_referral_config(
+            ctx,
+            referral_min_claims,
+            referral_min_days,
+            referrer_min_claims,
+            referral_ancestor_levels,
+            referral_ancestor_bps,
+            referral_decay_bps,
+        )
+    }
+
+    /// Admin sets per-referrer caps and the bonus expiry period.
+    pub fn update_referral_limits(
+        ctx: Context<UpdateConfig>,
+        referral_daily_cap: Option<u64>,
+        referral_lifetime_cap: Option<u64>,
+        referral_bonus_expiry_days: Option<u64>,
+    ) -> Result<()> {
+        handle_update_referral_limits(
+            ctx,
+            referral_daily_cap,
+            referral_lifetime_cap,
+            referral_bonus_expiry_days,
+        )
+    }
+
+    /// Anyone forfeits a referrer's bonus once it has expired.
+    pub fn expire_referral_bonus(
+        ctx: Context<ExpireReferralBonus>,
+        target_wallet: Pubkey,
+    ) -> Result<()> {
+        handle_expire_referral_bonus(ctx, target_wallet)
+    }
+
+    /// Admin sets the consecutive-day streak bonus schedule.
+    pub fn update_streak_config(
+        ctx: Context<UpdateConfig>,
+        streak_bonus_bps: Option<u16>,
+        streak_bonus_max_bps: Option<u16>,
+    ) -> Result<()> {
+        handle_update_streak_config(ctx, streak_bonus_bps, streak_bonus_max_bps)
+    }
+
+    /// Admin sets the claims, account age and referrals a wallet needs for a tier.
+    pub fn update_tier_requirements(
+        ctx: Context<UpdateConfig>,
+        tier_index: u8,
+        min_claims: Option<u64>,
+        min_account_age: Option<i64>,
+        min_referrals: Option<u64>,
+    ) -> Result<()> {
+        handle_update_tier_requirements(ctx, tier_index, min_claims, min_account_age, min_referrals)
+    }
+
+    /// Admin creates a top claimers, referrers or donors leaderboard.
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+        kind: u8,
+    ) -> Result<()> {
+        handle_initialize_leaderboard(ctx, kind)
+    }
+
+    /// Admin sets the low-treasury alert thresholds and auto-pause.
+    pub fn update_treasury_alerts(
+        ctx: Context<UpdateConfig>,
+        low_water_mark: Option<u64>,
+        high_water_mark: Option<u64>,
+        auto_pause_on_low: Option<bool>,
+    ) -> Result<()> {
+        handle_update_treasury_alerts(ctx, low_water_mark, high_water_mark, auto_pause_on_low)
+    }
+
+    /// Admin sets how payouts and the daily limit scale with treasury runway.
+    pub fn update_runway_scaling(
+        ctx: Context<UpdateConfig>,
+        scaling_mode: Option<u8>,
+        scaling_full_balance: Option<u64>,
+        scaling_floor_balance: Option<u64>,
+        scaling_floor_bps: Option<u16>,
+        scaling_step_thresholds: Option<[u64; 4]>,
+        scaling_step_bps: Option<[u16; 4]>,
+    ) -> Result<()> {
+        handle_update_runway_scaling(
+            ctx,
+            scaling_mode,
+            scaling_full_balance,
+            scaling_floor_balance,
+            scaling_floor_bps,
+            scaling_step_thresholds,
+            scaling_step_bps,
+        )
+    }
+
+    /// Admin sets the sponsored claim switch, relayer and refund limits.
+    pub fn update_sponsor_config(
+        ctx: Context<UpdateConfig>,
+        enabled: Option<bool>,
+        relayer: Option<Pubkey>,
+        reimbursement: Option<u64>,
+        daily_limit: Option<u64>,
+    ) -> Result<()> {
+        handle_update_sponsor_config(ctx, enabled, relayer, reimbursement, daily_limit)
+    }
+
+    /// Admin pauses or resumes the features in a PAUSE_* bitmask, with a reason code.
+    pub fn set_paused(
+        ctx: Context<UpdateConfig>,
+        features: u16,
+        paused: bool,
+        reason: u8,
+    ) -> Result<()> {
+        handle_set_paused(ctx, features, paused, reason)
+    }
+
+    /// Admin sets the claim outflow limit per window and the window length in minutes.
+    pub fn update_circuit_breaker(
+        ctx: Context<UpdateConfig>,
+        limit: Option<u64>,
+        window_minutes: Option<u8>,
+    ) -> Result<()> {
+        handle_update_circuit_breaker(ctx, limit, window_minutes)
+    }
+
+    /// Admin clears the outflow window and resumes claims paused by the circuit breaker.
+    pub fn reset_circuit_breaker(ctx: Context<UpdateConfig>) -> Result<()> {
+        handle_reset_circuit_breaker(ctx)
+    }
+
+    /// Admin adds or removes a program from the cpi_claim allowlist.
+    pub fn set_integrator(
+        ctx: Context<UpdateConfig>,
+        program_id: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        handle_set_integrator(ctx, program_id, allowed)
+    }
+
     /// Admin bans or unbans a wallet.
     pub fn block_wallet(
         ctx: Context<BlockWallet>,
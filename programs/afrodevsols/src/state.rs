@@ -1,17 +1,42 @@
 // programs/afrodevsols/src/state.rs
 
 use anchor_lang::prelude::*;
-use crate::constants::COOLDOWN_TIER_COUNT;
+use crate::errors::AfrodevsError;
+use crate::constants::{
+    COOLDOWN_TIER_COUNT, MAX_MILESTONES, MAX_NAME_LENGTH, MIN_REFERRAL_CODE_LENGTH,
+    SECONDS_PER_DAY, BPS_DENOMINATOR, RENT_RESERVE_LAMPORTS, LEADERBOARD_SIZE,
+    SCALING_STEP_COUNT, SCALING_MODE_LINEAR, SCALING_MODE_STEPS, MAX_INTEGRATORS,
+    PAUSE_FEATURE_COUNT, PAUSE_REASON_NONE, PAUSE_TIER_0, OUTFLOW_BUCKET_COUNT, SECONDS_PER_MINUTE,
+    CAMPAIGN_STATUS_ACTIVE, CAMPAIGN_STATUS_SWEPT, BOUNTY_STATUS_OPEN, BOUNTY_REVIEW_SECONDS,
+    GRANT_STATUS_PENDING, GRANT_STATUS_IN_PROGRESS, PAUSE_ALL, PAUSE_REASON_MANUAL,
+    SCALING_MODE_OFF, CONFIG_LAYOUT_VERSION,
+};
+
+// Copies a string into a fixed, zero-padded byte array,
+// truncating anything past N bytes.
+pub fn pad_bytes<const N: usize>(value: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let slice = value.as_bytes();
+    let copy_len = slice.len().min(N);
+    bytes[..copy_len].copy_from_slice(&slice[..copy_len]);
+    bytes
+}
+
+// Scales an amount by a basis-point multiplier, rounding down.
+pub fn apply_bps(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
 
 // ============================================================
 // FAUCET CONFIG
 // One per program. Global settings and running totals.
 // ============================================================
 #[account]
+#[derive(Debug)]
 pub struct FaucetConfig {
     pub authority: Pubkey,           // 32 — Admin wallet
     pub treasury: Pubkey,            // 32 — TreasuryVault PDA address
-    pub is_paused: bool,             // 1  — Kill switch
+    pub paused_features: u16,        // 2  — See PAUSE_* bits in constants.rs
     pub program_version: [u8; 3],   // 3  — [major, minor, patch]
 
     // Running totals
@@ -38,23 +63,413 @@ pub struct FaucetConfig {
     pub referral_bonus_referrer: u64,     // 8  — Bonus lamports queued for referrer
 
     pub bump: u8,                         // 1
+
+    // Referral unlock thresholds
+    pub referral_min_claims: u64,         // 8  — Claims the referred wallet must make
+    pub referral_min_days: u64,           // 8  — Days since the referred wallet's first claim
+    pub referrer_min_claims: u64,         // 8  — Claims a wallet needs before it can refer
+
+    // Multi-level referrals
+    pub referral_ancestor_levels: u8,     // 1  — Ancestors above the referrer that earn a share
+    pub referral_ancestor_bps: u16,       // 2  — Share of the referrer bonus for the first ancestor
+    pub referral_decay_bps: u16,          // 2  — Applied again for each level further up
+
+    // Referral rate limits (0 = unlimited)
+    pub referral_daily_cap: u64,          // 8  — New referrals per referrer per day
+    pub referral_lifetime_cap: u64,       // 8  — Confirmed referrals per referrer
+    pub referral_bonus_expiry_days: u64,  // 8  — Uncollected bonuses expire after this
+
+    // Liabilities
+    pub outstanding_referral_bonuses: u64, // 8 — Queued but not yet collected or expired
+
+    // Streak rewards
+    pub streak_bonus_bps: u16,            // 2  — Bonus per consecutive day after the first
+    pub streak_bonus_max_bps: u16,        // 2  — Cap on the total streak bonus
+
+    // Funding totals
+    pub total_funds_received: u64,        // 8  — Lifetime lamports in through fund_treasury
+    pub total_donations: u64,             // 8  — Lifetime fund_treasury calls
+    pub total_unique_funders: u64,        // 8  — Distinct funder wallets
+
+    // Low-treasury alerts
+    pub low_water_mark: u64,              // 8  — Alert when an outflow drops below this
+    pub high_water_mark: u64,             // 8  — Auto-pause lifts once funded back above this
+    pub auto_pause_on_low: bool,          // 1
+    pub pause_reasons: [u8; PAUSE_FEATURE_COUNT], // 9 — Reason per paused_features bit
+
+    // Runway scaling
+    pub scaling_mode: u8,                              // 1  — See SCALING_MODE_* in constants.rs
+    pub scaling_full_balance: u64,                     // 8  — Linear: full payout at or above
+    pub scaling_floor_balance: u64,                    // 8  — Linear: floor payout at or below
+    pub scaling_floor_bps: u16,                        // 2  — Linear: payout multiplier at the floor
+    pub scaling_step_thresholds: [u64; SCALING_STEP_COUNT], // 32 — Steps: applies below each balance
+    pub scaling_step_bps: [u16; SCALING_STEP_COUNT],   // 8  — Steps: multiplier for each threshold
+
+    // Sponsored claims
+    pub sponsored_claims_enabled: bool,   // 1
+    pub sponsor_relayer: Pubkey,          // 32 — Relayer refunded from the treasury (default = none)
+    pub sponsor_reimbursement: u64,       // 8  — Lamports refunded per sponsored claim
+    pub sponsor_daily_limit: u64,         // 8  — Refunds per day in lamports (0 = unlimited)
+    pub sponsor_reimbursed_today: u64,    // 8
+
+    // CPI claims
+    pub integrator_programs: [Pubkey; MAX_INTEGRATORS], // 256 — Programs allowed to call cpi_claim (default = empty slot)
+
+    // Velocity circuit breaker
+    pub circuit_breaker_limit: u64,                    // 8  — Max claim outflow per window in lamports (0 = off)
+    pub circuit_breaker_window_minutes: u8,            // 1  — Window length, 1..=OUTFLOW_BUCKET_COUNT
+    pub outflow_buckets: [u64; OUTFLOW_BUCKET_COUNT],  // 120 — Claim outflow per minute, indexed by minute % count
+    pub outflow_last_minute: i64,                      // 8  — Minute of the newest bucket
+
+    // Tier unlock requirements, per tier (0 = no requirement)
+    pub tier_min_claims: [u64; COOLDOWN_TIER_COUNT],      // 32 — Claims the wallet must already have made
+    pub tier_min_account_age: [i64; COOLDOWN_TIER_COUNT], // 32 — Seconds since the wallet's first claim
+    pub tier_min_referrals: [u64; COOLDOWN_TIER_COUNT],   // 32 — Confirmed referrals the wallet must have made
+
+    // PDA nonces for admin-created accounts
+    pub grant_nonce: u64,                 // 8  — Seeds the next GrantRecord
+    pub bounty_nonce: u64,                // 8  — Id of the next Bounty
+
+    // Leaderboards
+    pub leaderboards_initialized: u8,     // 1  — Bit per LEADERBOARD_KIND_* that has a board
+
+    pub layout_version: u8,               // 1  — CONFIG_LAYOUT_VERSION when last written
 }
 
 impl FaucetConfig {
     // Space calculation: 8 (discriminator) + sum of all fields above
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 3 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 3 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 1
+        + 8 + 8 + 8
+        + 1 + 2 + 2
+        + 8 + 8 + 8
+        + 8
+        + 2 + 2
+        + 8 + 8 + 8
+        + 8 + 8 + 1 + PAUSE_FEATURE_COUNT
+        + 1 + 8 + 8 + 2 + 32 + 8
+        + 1 + 32 + 8 + 8 + 8
+        + 32 * MAX_INTEGRATORS
+        + 8 + 1 + 8 * OUTFLOW_BUCKET_COUNT + 8
+        + (8 + 8 + 8) * COOLDOWN_TIER_COUNT
+        + 8 + 8
+        + 1
+        + 1;
 
     pub fn is_daily_reset_needed(&self, current_time: i64) -> bool {
         current_time >= self.daily_reset_timestamp + 86400
     }
 
+    // Starts a new day's counters once 24h have passed.
+    pub fn roll_daily_counters(&mut self, current_time: i64) {
+        if self.is_daily_reset_needed(current_time) {
+            self.daily_global_distributed = 0;
+            self.sponsor_reimbursed_today = 0;
+            self.daily_reset_timestamp = current_time;
+        }
+    }
+
+    // Today's distributed total as it reads after any pending reset.
+    pub fn daily_distributed_at(&self, current_time: i64) -> u64 {
+        if self.is_daily_reset_needed(current_time) { 0 } else { self.daily_global_distributed }
+    }
+
     pub fn get_tier_index(&self, amount: u64) -> Option<usize> {
-        for i in 0..COOLDOWN_TIER_COUNT {
-            if self.cooldown_tier_amounts[i] == amount {
-                return Some(i);
+        (0..COOLDOWN_TIER_COUNT).find(|&i| self.cooldown_tier_amounts[i] == amount)
+    }
+
+    // What a wallet still lacks to unlock a tier. `claimer_record` is
+    // None for a wallet that has never claimed.
+    pub fn tier_shortfall(
+        &self,
+        tier_index: usize,
+        claimer_record: Option<&ClaimerRecord>,
+        current_time: i64,
+    ) -> TierShortfall {
+        let (claims, account_age, referrals) = claimer_record
+            .map(|r| (r.total_claims, r.account_age(current_time), r.referral_count))
+            .unwrap_or_default();
+        TierShortfall {
+            claims: self.tier_min_claims[tier_index].saturating_sub(claims),
+            account_age: (self.tier_min_account_age[tier_index] - account_age).max(0),
+            referrals: self.tier_min_referrals[tier_index].saturating_sub(referrals),
+        }
+    }
+
+    // SOL the vault owes but still holds. Escrowed grants are not
+    // counted: their lamports already sit in their GrantRecords.
+    pub fn outstanding_liabilities(&self) -> u64 {
+        self.outstanding_referral_bonuses
+    }
+
+    // What claims, grants and withdrawals may spend without touching
+    // the rent reserve or SOL owed to referrers.
+    pub fn spendable_balance(&self, treasury_balance: u64) -> u64 {
+        treasury_balance
+            .saturating_sub(RENT_RESERVE_LAMPORTS)
+            .saturating_sub(self.outstanding_liabilities())
+    }
+
+    // Payout multiplier for the current vault balance. 10_000 when
+    // scaling is off or the treasury is healthy.
+    pub fn runway_multiplier_bps(&self, treasury_balance: u64) -> u16 {
+        match self.scaling_mode {
+            SCALING_MODE_LINEAR => {
+                if treasury_balance >= self.scaling_full_balance {
+                    BPS_DENOMINATOR
+                } else if treasury_balance <= self.scaling_floor_balance {
+                    self.scaling_floor_bps
+                } else {
+                    let range = (self.scaling_full_balance - self.scaling_floor_balance) as u128;
+                    let above_floor = (treasury_balance - self.scaling_floor_balance) as u128;
+                    let span = (BPS_DENOMINATOR - self.scaling_floor_bps) as u128;
+                    self.scaling_floor_bps + (span * above_floor / range) as u16
+                }
             }
+            SCALING_MODE_STEPS => (0..SCALING_STEP_COUNT)
+                .filter(|&i| treasury_balance < self.scaling_step_thresholds[i])
+                .map(|i| self.scaling_step_bps[i])
+                .min()
+                .unwrap_or(BPS_DENOMINATOR),
+            _ => BPS_DENOMINATOR,
+        }
+    }
+
+    // Refund owed to a relayer for one sponsored claim. Only the
+    // configured relayer is refunded, and never past the daily limit.
+    pub fn sponsor_reimbursement_for(&self, relayer: &Pubkey) -> u64 {
+        if self.sponsor_relayer == Pubkey::default() || *relayer != self.sponsor_relayer {
+            return 0;
+        }
+        if self.sponsor_daily_limit == 0 {
+            return self.sponsor_reimbursement;
+        }
+        self.sponsor_reimbursement
+            .min(self.sponsor_daily_limit.saturating_sub(self.sponsor_reimbursed_today))
+    }
+
+    pub fn is_paused(&self, feature: u16) -> bool {
+        self.paused_features & feature != 0
+    }
+
+    pub fn has_leaderboard(&self, kind: u8) -> bool {
+        self.leaderboards_initialized & (1 << kind) != 0
+    }
+
+    pub fn tier_pause_flag(tier_index: usize) -> u16 {
+        PAUSE_TIER_0 << tier_index
+    }
+
+    // Reason recorded for a single feature bit.
+    pub fn pause_reason(&self, feature: u16) -> u8 {
+        match feature.trailing_zeros() as usize {
+            bit if bit < PAUSE_FEATURE_COUNT && self.is_paused(feature) => self.pause_reasons[bit],
+            _ => PAUSE_REASON_NONE,
+        }
+    }
+
+    // Pauses or resumes every feature in `features`. Resuming clears
+    // the reason; `reason` is ignored.
+    pub fn set_paused(&mut self, features: u16, paused: bool, reason: u8) {
+        for bit in 0..PAUSE_FEATURE_COUNT {
+            let flag = 1u16 << bit;
+            if features & flag == 0 {
+                continue;
+            }
+            if paused {
+                self.paused_features |= flag;
+                self.pause_reasons[bit] = reason;
+            } else {
+                self.paused_features &= !flag;
+                self.pause_reasons[bit] = PAUSE_REASON_NONE;
+            }
+        }
+    }
+
+    // Features currently paused for `reason`.
+    pub fn features_paused_for(&self, reason: u8) -> u16 {
+        (0..PAUSE_FEATURE_COUNT)
+            .map(|bit| 1u16 << bit)
+            .filter(|flag| self.is_paused(*flag) && self.pause_reason(*flag) == reason)
+            .fold(0, |mask, flag| mask | flag)
+    }
+
+    fn outflow_bucket(minute: i64) -> usize {
+        minute.rem_euclid(OUTFLOW_BUCKET_COUNT as i64) as usize
+    }
+
+    // Adds a payout to the current minute's bucket, clearing buckets
+    // for any minutes that passed with no outflow. Returns the total
+    // over the breaker window ending now.
+    pub fn record_outflow(&mut self, amount: u64, current_time: i64) -> u64 {
+        let minute = current_time.div_euclid(SECONDS_PER_MINUTE);
+        let elapsed = minute.saturating_sub(self.outflow_last_minute);
+
+        if elapsed >= OUTFLOW_BUCKET_COUNT as i64 {
+            self.outflow_buckets = [0u64; OUTFLOW_BUCKET_COUNT];
+        } else {
+            for passed in (self.outflow_last_minute + 1)..=minute {
+                self.outflow_buckets[Self::outflow_bucket(passed)] = 0;
+            }
+        }
+        self.outflow_last_minute = self.outflow_last_minute.max(minute);
+
+        let bucket = &mut self.outflow_buckets[Self::outflow_bucket(minute)];
+        *bucket = bucket.saturating_add(amount);
+
+        (0..self.circuit_breaker_window_minutes as i64)
+            .map(|back| self.outflow_buckets[Self::outflow_bucket(self.outflow_last_minute - back)])
+            .fold(0u64, u64::saturating_add)
+    }
+
+    pub fn is_integrator(&self, program_id: &Pubkey) -> bool {
+        *program_id != Pubkey::default() && self.integrator_programs.contains(program_id)
+    }
+
+    pub fn referral_min_seconds(&self) -> i64 {
+        (self.referral_min_days as i64).saturating_mul(SECONDS_PER_DAY)
+    }
+
+    pub fn is_eligible_referrer(&self, referrer: &ClaimerRecord, referrer_key: &Pubkey) -> bool {
+        referrer.wallet == *referrer_key
+            && !referrer.is_blocked
+            && referrer.total_claims >= self.referrer_min_claims
+    }
+
+    // Level 0 is the first ancestor above the direct referrer and
+    // takes referral_ancestor_bps of the bonus. Every later level
+    // takes referral_decay_bps of the level below it.
+    pub fn ancestor_share(&self, base: u64, level: usize) -> u64 {
+        let bps = if level == 0 { self.referral_ancestor_bps } else { self.referral_decay_bps };
+        apply_bps(base, bps)
+    }
+
+    pub fn is_referral_lifetime_cap_reached(&self, referral_count: u64) -> bool {
+        self.referral_lifetime_cap > 0 && referral_count >= self.referral_lifetime_cap
+    }
+
+    pub fn is_referral_bonus_expired(&self, referrer: &ClaimerRecord, current_time: i64) -> bool {
+        self.referral_bonus_expiry_days > 0
+            && referrer.pending_referral_bonus > 0
+            && current_time - referrer.pending_bonus_since
+                >= (self.referral_bonus_expiry_days as i64).saturating_mul(SECONDS_PER_DAY)
+    }
+
+    // Day one of a streak earns nothing; each further day adds
+    // streak_bonus_bps of the claim, up to streak_bonus_max_bps.
+    pub fn streak_bonus(&self, amount: u64, streak: u64) -> u64 {
+        let bps = (streak.saturating_sub(1))
+            .saturating_mul(self.streak_bonus_bps as u64)
+            .min(self.streak_bonus_max_bps as u64);
+        apply_bps(amount, bps as u16)
+    }
+
+    pub fn is_referral_unlocked(&self, referred: &ClaimerRecord, current_time: i64) -> bool {
+        referred.total_claims >= self.referral_min_claims
+            && current_time - referred.created_at >= self.referral_min_seconds()
+    }
+}
+
+// ============================================================
+// FAUCET CONFIG V0
+// The layout deployed before layout_version existed. Only read
+// by migrate_config, which rewrites it as the current layout.
+// ============================================================
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FaucetConfigV0 {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub is_paused: bool,
+    pub program_version: [u8; 3],
+    pub total_sol_distributed: u64,
+    pub total_claims: u64,
+    pub total_unique_claimers: u64,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub cooldown_tier_amounts: [u64; 4],
+    pub cooldown_tier_seconds: [i64; 4],
+    pub daily_global_limit: u64,
+    pub daily_global_distributed: u64,
+    pub daily_reset_timestamp: i64,
+    pub referral_enabled: bool,
+    pub referral_bonus_claimer: u64,
+    pub referral_bonus_referrer: u64,
+    pub bump: u8,
+}
+
+impl FaucetConfigV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 3 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 1;
+
+    // Keeps every setting the old layout had. Everything added since
+    // gets the value initialize would give it, and a paused faucet
+    // stays fully paused.
+    pub fn upgrade(self) -> FaucetConfig {
+        let (paused_features, pause_reason) = if self.is_paused {
+            (PAUSE_ALL, PAUSE_REASON_MANUAL)
+        } else {
+            (0, PAUSE_REASON_NONE)
+        };
+        FaucetConfig {
+            authority: self.authority,
+            treasury: self.treasury,
+            paused_features,
+            program_version: self.program_version,
+            total_sol_distributed: self.total_sol_distributed,
+            total_claims: self.total_claims,
+            total_unique_claimers: self.total_unique_claimers,
+            min_amount: self.min_amount,
+            max_amount: self.max_amount,
+            cooldown_tier_amounts: self.cooldown_tier_amounts,
+            cooldown_tier_seconds: self.cooldown_tier_seconds,
+            daily_global_limit: self.daily_global_limit,
+            daily_global_distributed: self.daily_global_distributed,
+            daily_reset_timestamp: self.daily_reset_timestamp,
+            referral_enabled: self.referral_enabled,
+            referral_bonus_claimer: self.referral_bonus_claimer,
+            referral_bonus_referrer: self.referral_bonus_referrer,
+            bump: self.bump,
+            referral_min_claims: 1,
+            referral_min_days: 0,
+            referrer_min_claims: 1,
+            referral_ancestor_levels: 0,
+            referral_ancestor_bps: 0,
+            referral_decay_bps: 0,
+            referral_daily_cap: 0,
+            referral_lifetime_cap: 0,
+            referral_bonus_expiry_days: 0,
+            outstanding_referral_bonuses: 0,
+            streak_bonus_bps: 0,
+            streak_bonus_max_bps: 0,
+            total_funds_received: 0,
+            total_donations: 0,
+            total_unique_funders: 0,
+            low_water_mark: 0,
+            high_water_mark: 0,
+            auto_pause_on_low: false,
+            pause_reasons: [pause_reason; PAUSE_FEATURE_COUNT],
+            scaling_mode: SCALING_MODE_OFF,
+            scaling_full_balance: 0,
+            scaling_floor_balance: 0,
+            scaling_floor_bps: BPS_DENOMINATOR,
+            scaling_step_thresholds: [0u64; SCALING_STEP_COUNT],
+            scaling_step_bps: [BPS_DENOMINATOR; SCALING_STEP_COUNT],
+            sponsored_claims_enabled: false,
+            sponsor_relayer: Pubkey::default(),
+            sponsor_reimbursement: 0,
+            sponsor_daily_limit: 0,
+            sponsor_reimbursed_today: 0,
+            integrator_programs: [Pubkey::default(); MAX_INTEGRATORS],
+            circuit_breaker_limit: 0,
+            circuit_breaker_window_minutes: OUTFLOW_BUCKET_COUNT as u8,
+            outflow_buckets: [0u64; OUTFLOW_BUCKET_COUNT],
+            outflow_last_minute: 0,
+            tier_min_claims: [0u64; COOLDOWN_TIER_COUNT],
+            tier_min_account_age: [0i64; COOLDOWN_TIER_COUNT],
+            tier_min_referrals: [0u64; COOLDOWN_TIER_COUNT],
+            grant_nonce: 0,
+            bounty_nonce: 0,
+            leaderboards_initialized: 0,
+            layout_version: CONFIG_LAYOUT_VERSION,
         }
-        None
     }
 }
 
@@ -63,6 +478,7 @@ impl FaucetConfig {
 // One per user wallet. Created on first claim.
 // ============================================================
 #[account]
+#[derive(Debug)]
 pub struct ClaimerRecord {
     pub wallet: Pubkey,                          // 32
     pub total_claimed: u64,                      // 8  — Lifetime lamports received
@@ -77,10 +493,49 @@ pub struct ClaimerRecord {
     pub created_at: i64,                         // 8
     pub last_claim_slot: u64,                    // 8  — For double-spend prevention
     pub bump: u8,                                // 1
+    pub pending_bonus_since: i64,                // 8  — Oldest uncollected bonus, 0 if none
+    pub referral_day: i64,                       // 8  — Day number of referrals_today
+    pub referrals_today: u64,                    // 8  — New referrals on referral_day
+    pub current_streak: u64,                     // 8  — Consecutive days with a claim
+    pub longest_streak: u64,                     // 8
+    pub last_claim_day: i64,                     // 8  — DailyStats::day_number of last claim
 }
 
 impl ClaimerRecord {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 1 + 33 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 1 + 33 + 8 + 8 + 8 + 8 + 1
+        + 8 + 8 + 8
+        + 8 + 8 + 8;
+    // Before the referral limit and streak fields were appended
+    pub const V0_LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 1 + 33 + 8 + 8 + 8 + 8 + 1;
+
+    // Streak after a claim on `day`: unchanged for a same-day claim,
+    // extended after yesterday's claim, otherwise restarted.
+    pub fn next_streak(&self, day: i64) -> u64 {
+        if self.last_claim_day == day {
+            self.current_streak.max(1)
+        } else if self.last_claim_day == day - 1 {
+            self.current_streak.saturating_add(1)
+        } else {
+            1
+        }
+    }
+
+    pub fn referrals_on_day(&self, day: i64) -> u64 {
+        if self.referral_day == day { self.referrals_today } else { 0 }
+    }
+
+    // Expiry runs from the oldest uncollected bonus, so the clock
+    // only restarts once the balance is collected or expired. Use the
+    // instruction helper, which forfeits an expired balance first.
+    pub fn queue_referral_bonus(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        if self.pending_referral_bonus == 0 {
+            self.pending_bonus_since = current_time;
+        }
+        self.pending_referral_bonus = self.pending_referral_bonus
+            .checked_add(amount)
+            .ok_or(AfrodevsError::Overflow)?;
+        Ok(())
+    }
 
     pub fn is_cooldown_active(&self, tier_index: usize, current_time: i64) -> bool {
         self.cooldown_ends_at[tier_index] > current_time
@@ -89,45 +544,228 @@ impl ClaimerRecord {
     pub fn cooldown_remaining(&self, tier_index: usize, current_time: i64) -> i64 {
         (self.cooldown_ends_at[tier_index] - current_time).max(0)
     }
+
+    // Seconds since the first claim, 0 before it.
+    pub fn account_age(&self, current_time: i64) -> i64 {
+        if self.total_claims == 0 { 0 } else { (current_time - self.created_at).max(0) }
+    }
 }
 
 // ============================================================
 // REFERRAL RECORD
-// One per referral relationship.
+// One per referral relationship, keyed by the referred wallet.
+// Stays pending until the referred wallet meets the unlock
+// thresholds in FaucetConfig.
 // ============================================================
 #[account]
+#[derive(Debug)]
 pub struct ReferralRecord {
     pub referrer: Pubkey,               // 32
     pub referred: Pubkey,               // 32
-    pub confirmed_at: i64,              // 8
+    pub confirmed_at: i64,              // 8  — 0 while pending
     pub bonus_paid_to_referrer: bool,   // 1
     pub bonus_paid_to_referred: bool,   // 1
     pub bump: u8,                       // 1
+    pub created_at: i64,                // 8  — Referred wallet's first claim
+    pub referrer_bonus: u64,            // 8  — Queued for the referrer on confirmation
+    pub referred_bonus: u64,            // 8  — Paid with the referred wallet's first claim
 }
 
 impl ReferralRecord {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 1 + 8 + 8 + 8;
+    // Before pending referrals and per-referral bonus amounts
+    pub const V0_LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 1;
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at != 0
+    }
+}
+
+// ============================================================
+// REFERRAL CODE
+// One per registered short code. Maps the code to its owner.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct ReferralCode {
+    pub owner: Pubkey,                  // 32
+    pub code: [u8; MAX_NAME_LENGTH],    // 30 — Zero padded
+    pub created_at: i64,                // 8
+    pub times_used: u64,                // 8  — Referral claims resolved through this code
+    pub bump: u8,                       // 1
+}
+
+impl ReferralCode {
+    pub const LEN: usize = 8 + 32 + MAX_NAME_LENGTH + 8 + 8 + 1;
+
+    // Lowercase ASCII letters, digits, '-' and '_' only, so codes
+    // can't be spoofed with lookalike characters.
+    pub fn is_valid_code(code: &str) -> bool {
+        (MIN_REFERRAL_CODE_LENGTH..=MAX_NAME_LENGTH).contains(&code.len())
+            && code
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+    }
+
+    pub fn encode_code(code: &str) -> [u8; MAX_NAME_LENGTH] {
+        pad_bytes(code)
+    }
+}
+
+// ============================================================
+// FUNDER RECORD
+// One per wallet that has funded the treasury.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct FunderRecord {
+    pub funder: Pubkey,                         // 32
+    pub total_donated: u64,                     // 8  — Lifetime lamports donated
+    pub donation_count: u64,                    // 8
+    pub first_donation_at: i64,                 // 8
+    pub last_donation_at: i64,                  // 8
+    pub display_name: [u8; MAX_NAME_LENGTH],    // 30 — Zero padded, empty if anonymous
+    pub bump: u8,                               // 1
+}
+
+impl FunderRecord {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + MAX_NAME_LENGTH + 1;
 }
 
 // ============================================================
 // GRANT RECORD
 // Created for every admin special_grant or bulk_grant.
+// Escrowed grants hold their lamports in this account until
+// the recipient accepts or the grant expires.
 // ============================================================
 #[account]
+#[derive(Debug)]
 pub struct GrantRecord {
     pub authority: Pubkey,    // 32
     pub recipient: Pubkey,    // 32
     pub amount: u64,          // 8
     pub reason: [u8; 64],    // 64 — Fixed size, padded with zeros
     pub timestamp: i64,       // 8
-    pub grant_type: u8,       // 1  — 0 = special, 1 = bulk
+    pub grant_type: u8,       // 1  — 0 = special, 1 = bulk, 2 = milestone
     pub batch_id: i64,        // 8  — 0 for special grants, timestamp for bulk
     pub is_public: bool,      // 1
     pub bump: u8,             // 1
+    pub status: u8,           // 1  — See GRANT_STATUS_* in constants.rs
+    pub expires_at: i64,      // 8  — 0 for push grants
+    pub settled_at: i64,      // 8  — When paid, accepted or expired
 }
 
 impl GrantRecord {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 64 + 8 + 1 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 64 + 8 + 1 + 8 + 1 + 1 + 1 + 8 + 8;
+    // Before escrow, when every grant was paid on creation
+    pub const V0_LEN: usize = 8 + 32 + 32 + 8 + 64 + 8 + 1 + 8 + 1 + 1;
+
+    pub fn encode_reason(reason: &str) -> [u8; 64] {
+        pad_bytes(reason)
+    }
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+
+    // No SOL left in escrow and nothing more will be released.
+    pub fn is_settled(&self) -> bool {
+        !matches!(self.status, GRANT_STATUS_PENDING | GRANT_STATUS_IN_PROGRESS)
+    }
+}
+
+// ============================================================
+// GRANT MILESTONES
+// Tranche schedule for a milestone grant. The escrowed SOL
+// lives in the linked GrantRecord.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct GrantMilestones {
+    pub grant: Pubkey,                                   // 32 — Linked GrantRecord
+    pub milestone_count: u8,                             // 1
+    pub amounts: [u64; MAX_MILESTONES],                  // 64
+    pub description_hashes: [[u8; 32]; MAX_MILESTONES],  // 256
+    pub released_at: [i64; MAX_MILESTONES],              // 64 — 0 while outstanding
+    pub released_total: u64,                             // 8
+    pub bump: u8,                                        // 1
+}
+
+impl GrantMilestones {
+    pub const LEN: usize = 8 + 32 + 1 + 64 + 256 + 64 + 8 + 1;
+
+    pub fn is_released(&self, index: usize) -> bool {
+        self.released_at[index] != 0
+    }
+
+    pub fn all_released(&self) -> bool {
+        (0..self.milestone_count as usize).all(|i| self.is_released(i))
+    }
+
+    pub fn outstanding_amount(&self) -> u64 {
+        (0..self.milestone_count as usize)
+            .filter(|&i| !self.is_released(i))
+            .map(|i| self.amounts[i])
+            .sum()
+    }
+}
+
+// ============================================================
+// LEADERBOARD
+// One per kind. Top LEADERBOARD_SIZE wallets, highest first.
+// Scores only ever grow, so entries only move up.
+// ============================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,   // 32 — Pubkey::default() for an empty slot
+    pub score: u64,       // 8
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Leaderboard {
+    pub kind: u8,                                       // 1  — See LEADERBOARD_KIND_*
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],  // 400
+    pub updated_at: i64,                                // 8
+    pub bump: u8,                                       // 1
+}
+
+impl Leaderboard {
+    pub const LEN: usize = 8 + 1 + (32 + 8) * LEADERBOARD_SIZE + 8 + 1;
+
+    pub fn score_of(&self, wallet: &Pubkey) -> Option<u64> {
+        self.entries.iter().find(|e| e.wallet == *wallet).map(|e| e.score)
+    }
+
+    pub fn record(&mut self, wallet: Pubkey, score: u64, current_time: i64) {
+        if score == 0 {
+            return;
+        }
+
+        let last = LEADERBOARD_SIZE - 1;
+        let position = match self.entries.iter().position(|e| e.wallet == wallet) {
+            Some(i) => i,
+            None => {
+                let lowest = &self.entries[last];
+                if lowest.wallet != Pubkey::default() && lowest.score >= score {
+                    return;
+                }
+                last
+            }
+        };
+
+        self.entries[position] = LeaderboardEntry { wallet, score };
+
+        let mut i = position;
+        while i > 0
+            && (self.entries[i - 1].wallet == Pubkey::default() || self.entries[i - 1].score < score)
+        {
+            self.entries.swap(i - 1, i);
+            i -= 1;
+        }
+
+        self.updated_at = current_time;
+    }
 }
 
 // ============================================================
@@ -135,6 +773,7 @@ impl GrantRecord {
 // One per calendar day. Created on first claim of each day.
 // ============================================================
 #[account]
+#[derive(Debug)]
 pub struct DailyStats {
     pub date: i64,                    // 8  — Unix day number (timestamp / 86400)
     pub total_distributed: u64,       // 8
@@ -150,4 +789,258 @@ impl DailyStats {
     pub fn day_number(timestamp: i64) -> i64 {
         timestamp / 86400
     }
-}
\ No newline at end of file
+}
+// ============================================================
+// CAMPAIGN
+// A time-bounded faucet with its own tiers and rules. The budget
+// is escrowed in this account when the campaign is created and
+// whatever is left is swept back to the treasury at the end.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct Campaign {
+    pub campaign_id: u64,                              // 8  — PDA seed, assigned from config.bounty_nonce
+    pub authority: Pubkey,                             // 32
+    pub name: [u8; MAX_NAME_LENGTH],                   // 30 — Padded with zeros
+    pub budget: u64,                                   // 8  — Lamports escrowed at creation
+    pub starts_at: i64,                                // 8
+    pub ends_at: i64,                                  // 8
+    pub tier_amounts: [u64; COOLDOWN_TIER_COUNT],      // 32 — Claimable amounts (0 = unused slot)
+    pub cooldown_seconds: i64,                         // 8  — Between claims by one wallet
+    pub per_wallet_limit: u64,                         // 8  — Lamports per wallet (0 = no limit)
+    pub allowlist_root: [u8; 32],                      // 32 — Merkle root of wallets (all zero = open)
+    pub status: u8,                                    // 1  — See CAMPAIGN_STATUS_* in constants.rs
+
+    // Stats
+    pub total_claimed: u64,                            // 8
+    pub claim_count: u64,                              // 8
+    pub unique_claimers: u64,                          // 8
+    pub swept_amount: u64,                             // 8
+    pub created_at: i64,                               // 8
+    pub closed_at: i64,                                // 8  — When closed or swept, 0 while running
+    pub bump: u8,                                      // 1
+}
+
+impl Campaign {
+    pub const LEN: usize = 8 + 8 + 32 + MAX_NAME_LENGTH + 8 + 8 + 8 + 32 + 8 + 8 + 32 + 1
+        + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn is_tier(&self, amount: u64) -> bool {
+        amount > 0 && self.tier_amounts.contains(&amount)
+    }
+
+    pub fn is_open(&self, current_time: i64) -> bool {
+        self.status == CAMPAIGN_STATUS_ACTIVE
+            && current_time >= self.starts_at
+            && current_time < self.ends_at
+    }
+
+    // Over once closed by the authority or past ends_at, and not yet swept.
+    pub fn is_sweepable(&self, current_time: i64) -> bool {
+        self.status != CAMPAIGN_STATUS_SWEPT
+            && (self.status != CAMPAIGN_STATUS_ACTIVE || current_time >= self.ends_at)
+    }
+
+    pub fn remaining_budget(&self) -> u64 {
+        self.budget.saturating_sub(self.total_claimed)
+    }
+
+    // Sorted-pair sha256 Merkle proof. Leaves are sha256(wallet).
+    pub fn is_allowlisted(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if self.allowlist_root == [0u8; 32] {
+            return true;
+        }
+        let leaf = solana_sha256_hasher::hash(wallet.as_ref()).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            solana_sha256_hasher::hashv(&[&left, &right]).to_bytes()
+        });
+        root == self.allowlist_root
+    }
+}
+
+// ============================================================
+// CAMPAIGN CLAIM RECORD
+// One per wallet per campaign.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct CampaignClaimRecord {
+    pub campaign: Pubkey,             // 32
+    pub wallet: Pubkey,               // 32
+    pub total_claimed: u64,           // 8
+    pub claim_count: u64,             // 8
+    pub last_claim_at: i64,           // 8
+    pub bump: u8,                     // 1
+}
+
+impl CampaignClaimRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+}
+
+// ============================================================
+// VOUCHER
+// A paper code worth a fixed amount, paid from the treasury at
+// redemption. The code is the seed of an ed25519 keypair and
+// the voucher is keyed by its public key. Redeeming takes a
+// signature by that key over the redeemer, so the code never
+// appears on chain and a seen redemption can't be replayed for
+// another wallet.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct Voucher {
+    pub code_key: Pubkey,             // 32 — Public key of the code's keypair, also the PDA seed
+    pub authority: Pubkey,            // 32
+    pub amount: u64,                  // 8  — Lamports per redemption, before runway scaling
+    pub expires_at: i64,              // 8
+    pub max_uses: u32,                // 4
+    pub uses: u32,                    // 4
+    pub total_redeemed: u64,          // 8
+    pub created_at: i64,              // 8
+    pub bump: u8,                     // 1
+}
+
+impl Voucher {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 4 + 4 + 8 + 8 + 1;
+
+    // The ed25519 seed for a code's keypair.
+    pub fn hash_code(code: &str) -> [u8; 32] {
+        solana_sha256_hasher::hash(code.as_bytes()).to_bytes()
+    }
+
+    // What the code's key signs to redeem `voucher` for `redeemer`.
+    pub fn redemption_message(voucher: &Pubkey, redeemer: &Pubkey) -> [u8; 64] {
+        let mut message = [0u8; 64];
+        message[..32].copy_from_slice(voucher.as_ref());
+        message[32..].copy_from_slice(redeemer.as_ref());
+        message
+    }
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+
+    pub fn is_used_up(&self) -> bool {
+        self.uses >= self.max_uses
+    }
+}
+
+// ============================================================
+// VOUCHER REDEMPTION
+// One per wallet per voucher. Stops one holder from draining a
+// multi-use voucher.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct VoucherRedemption {
+    pub voucher: Pubkey,              // 32
+    pub wallet: Pubkey,               // 32
+    pub amount: u64,                  // 8  — Lamports paid, after runway scaling
+    pub redeemed_at: i64,             // 8  — 0 until redeemed
+    pub bump: u8,                     // 1
+}
+
+impl VoucherRedemption {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
+}
+
+// ============================================================
+// BOUNTY
+// A posted task with its reward escrowed in this account until
+// it is awarded or returned to the treasury.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct Bounty {
+    pub bounty_id: u64,               // 8  — PDA seed, assigned from config.bounty_nonce
+    pub authority: Pubkey,            // 32
+    pub reward: u64,                  // 8
+    pub deadline: i64,                // 8  — Last moment to submit
+    pub description_hash: [u8; 32],   // 32 — Hash of the off-chain task description
+    pub status: u8,                   // 1  — See BOUNTY_STATUS_* in constants.rs
+    pub submission_count: u32,        // 4
+    pub winner: Pubkey,               // 32 — Default until awarded
+    pub created_at: i64,              // 8
+    pub settled_at: i64,              // 8  — When awarded or expired
+    pub bump: u8,                     // 1
+}
+
+impl Bounty {
+    pub const LEN: usize = 8 + 8 + 32 + 8 + 8 + 32 + 1 + 4 + 32 + 8 + 8 + 1;
+
+    pub fn is_accepting_submissions(&self, current_time: i64) -> bool {
+        self.status == BOUNTY_STATUS_OPEN && current_time < self.deadline
+    }
+
+    // Unawarded once the deadline and the review period have passed.
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.status == BOUNTY_STATUS_OPEN
+            && current_time >= self.deadline.saturating_add(BOUNTY_REVIEW_SECONDS)
+    }
+}
+
+// ============================================================
+// BOUNTY SUBMISSION
+// One per submitter per bounty. Can be revised before the
+// deadline.
+// ============================================================
+#[account]
+#[derive(Debug)]
+pub struct BountySubmission {
+    pub bounty: Pubkey,               // 32
+    pub submitter: Pubkey,            // 32
+    pub work_uri_hash: [u8; 32],      // 32 — Hash of the URI pointing at the work
+    pub submitted_at: i64,            // 8  — Latest revision
+    pub bump: u8,                     // 1
+}
+
+impl BountySubmission {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+}
+
+// ============================================================
+// ELIGIBILITY RESULT
+// Returned by check_eligibility. Not stored on chain.
+// ============================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct EligibilityResult {
+    pub eligible: bool,
+    pub error_code: u32,                  // First failing AfrodevsError code, 0 if eligible
+    pub tier_index: Option<u8>,           // None if the amount is not a tier
+    pub payout: u64,                      // Lamports after runway scaling, before bonuses
+    pub runway_multiplier_bps: u16,
+    pub cooldown_remaining: [i64; 4],     // Seconds left on each tier's cooldown
+    pub next_eligible_at: i64,            // Earliest time the failing check could pass
+    pub daily_remaining: u64,             // Lamports left in today's scaled global limit
+    pub treasury_headroom: u64,           // Spendable vault balance
+    pub tier_shortfall: TierShortfall,    // Missing unlock requirements for the tier
+}
+
+// ============================================================
+// TIER SHORTFALL
+// What a wallet still lacks to unlock a tier. All zero when the
+// tier is unlocked. Not stored on chain.
+// ============================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TierShortfall {
+    pub claims: u64,                      // More claims needed
+    pub account_age: i64,                 // More seconds since the first claim
+    pub referrals: u64,                   // More confirmed referrals needed
+}
+
+impl TierShortfall {
+    pub fn is_met(&self) -> bool {
+        *self == TierShortfall::default()
+    }
+}
+
+// ============================================================
+// CPI CLAIM RESULT
+// Returned by cpi_claim to the calling program.
+// ============================================================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct CpiClaimResult {
+    pub amount_paid: u64,                 // Lamports sent to the destination, bonuses included
+    pub next_eligible_at: i64,            // When the claimed tier's cooldown ends
+}
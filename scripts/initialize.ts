@@ -79,7 +79,7 @@ async function main() {
 
     console.log("── On-chain state ──────────────────────────");
     console.log("Authority:      ", config.authority.toString());
-    console.log("Is paused:      ", config.isPaused);
+    console.log("Paused features:", config.pausedFeatures);
     console.log("Min amount:     ", config.minAmount.toNumber() / LAMPORTS_PER_SOL, "SOL");
     console.log("Max amount:     ", config.maxAmount.toNumber() / LAMPORTS_PER_SOL, "SOL");
     console.log("Referral:       ", config.referralEnabled);
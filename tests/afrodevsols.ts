@@ -2,8 +2,15 @@
 
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey, LAMPORTS_PER_SOL, Keypair } from "@solana/web3.js";
+import {
+  PublicKey,
+  LAMPORTS_PER_SOL,
+  Keypair,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import { assert } from "chai";
+import { createHash } from "crypto";
 import { Afrodevsols } from "../target/types/afrodevsols";
 
 // ── HELPERS ──────────────────────────────────────────────────
@@ -29,6 +36,12 @@ function getPDA(seeds: Buffer[], programId: PublicKey): PublicKey {
   return PublicKey.findProgramAddressSync(seeds, programId)[0];
 }
 
+// FaucetConfig.paused_features bits (constants.rs)
+const PAUSE_CLAIMS = 1 << 0;
+const PAUSE_TIER_0 = 1 << 1;
+const PAUSE_REFERRAL_PAYOUT = 1 << 6;
+const PAUSE_WITHDRAWALS = 1 << 8;
+
 // ── SETUP ────────────────────────────────────────────────────
 
 describe("afrodevsols", () => {
@@ -40,6 +53,14 @@ describe("afrodevsols", () => {
   // PDAs
   const faucetConfigPDA = getPDA([Buffer.from("faucet_config")], program.programId);
   const treasuryVaultPDA = getPDA([Buffer.from("treasury_vault")], program.programId);
+  const claimersBoardPDA = getPDA(
+    [Buffer.from("leaderboard"), Buffer.from([0])],
+    program.programId
+  );
+  const donorsBoardPDA = getPDA(
+    [Buffer.from("leaderboard"), Buffer.from([2])],
+    program.programId
+  );
 
   // Test wallets
   const user1 = Keypair.generate();
@@ -86,7 +107,7 @@ describe("afrodevsols", () => {
     const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
 
     assert.equal(config.authority.toString(), authority.publicKey.toString());
-    assert.equal(config.isPaused, false);
+    assert.equal(config.pausedFeatures, 0);
     assert.equal(config.totalClaims.toString(), "0");
     assert.equal(config.totalUniqueClaimers.toString(), "0");
     assert.equal(config.referralEnabled, true);
@@ -106,6 +127,11 @@ describe("afrodevsols", () => {
       .accounts({
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
+        funderRecord: getPDA(
+          [Buffer.from("funder"), authority.publicKey.toBuffer()],
+          program.programId
+        ),
+        donorsLeaderboard: null,
         funder: authority.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -133,6 +159,10 @@ describe("afrodevsols", () => {
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
         claimerRecord: claimerRecordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
         claimer: user1.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -168,6 +198,10 @@ describe("afrodevsols", () => {
           faucetConfig: faucetConfigPDA,
           treasuryVault: treasuryVaultPDA,
           claimerRecord: claimerRecordPDA,
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
           claimer: user1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -201,6 +235,10 @@ describe("afrodevsols", () => {
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
         claimerRecord: claimerRecordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
         claimer: user1.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -231,6 +269,10 @@ describe("afrodevsols", () => {
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
         claimerRecord: user3RecordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
         claimer: user3.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -243,6 +285,11 @@ describe("afrodevsols", () => {
       program.programId
     );
 
+    const user2ReferralPDA = getPDA(
+      [Buffer.from("referral"), user2.publicKey.toBuffer()],
+      program.programId
+    );
+
     const balanceBefore = await provider.connection.getBalance(user2.publicKey);
 
     await program.methods
@@ -251,6 +298,10 @@ describe("afrodevsols", () => {
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
         claimerRecord: user2RecordPDA,
+        referralRecord: user2ReferralPDA,
+        referrerRecord: user3RecordPDA,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
         claimer: user2.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -265,6 +316,12 @@ describe("afrodevsols", () => {
     assert.isAbove(received, 0.14 * LAMPORTS_PER_SOL, "Should include referral bonus");
     assert.equal(record.referredBy?.toString(), user3.publicKey.toString());
 
+    // Default unlock threshold is one claim, so user3 is credited right away
+    const referral = await program.account.referralRecord.fetch(user2ReferralPDA);
+    const referrerRecord = await program.account.claimerRecord.fetch(user3RecordPDA);
+    assert.isAbove(referral.confirmedAt.toNumber(), 0);
+    assert.equal(referrerRecord.pendingReferralBonus.toString(), REFERRAL_BONUS_REFERRER.toString());
+
     console.log("    user2 received:", received / LAMPORTS_PER_SOL, "SOL (with referral bonus)");
   });
 
@@ -288,6 +345,10 @@ describe("afrodevsols", () => {
         faucetConfig: faucetConfigPDA,
         treasuryVault: treasuryVaultPDA,
         claimerRecord: recordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
         claimer: newUser.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -309,9 +370,9 @@ describe("afrodevsols", () => {
   // TEST 8: PAUSE — blocks all claims
   // ──────────────────────────────────────────────────────────
   it("✅ pause blocks claims, unpause restores them", async () => {
-    // Pause
+    // Pause claims
     await program.methods
-      .updateConfig(true, null, null, null, null, null, null, null, null, null)
+      .setPaused(PAUSE_CLAIMS, true, 1)
       .accounts({
         faucetConfig: faucetConfigPDA,
         authority: authority.publicKey,
@@ -332,6 +393,10 @@ describe("afrodevsols", () => {
           faucetConfig: faucetConfigPDA,
           treasuryVault: treasuryVaultPDA,
           claimerRecord: recordPDA,
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
           claimer: freshUser.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -344,7 +409,7 @@ describe("afrodevsols", () => {
 
     // Unpause
     await program.methods
-      .updateConfig(false, null, null, null, null, null, null, null, null, null)
+      .setPaused(PAUSE_CLAIMS, false, 0)
       .accounts({
         faucetConfig: faucetConfigPDA,
         authority: authority.publicKey,
@@ -352,7 +417,7 @@ describe("afrodevsols", () => {
       .rpc();
 
     const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
-    assert.equal(config.isPaused, false);
+    assert.equal(config.pausedFeatures, 0);
     console.log("    Pause/unpause works ✓");
   });
 
@@ -398,7 +463,7 @@ describe("afrodevsols", () => {
   // ──────────────────────────────────────────────────────────
   it("✅ admin can special grant any amount bypassing all rules", async () => {
     const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
-    const nonce = config.totalClaims;
+    const nonce = config.grantNonce;
 
     const grantRecordPDA = getPDA(
       [
@@ -463,7 +528,7 @@ describe("afrodevsols", () => {
   it("✅ rejects unauthorized admin calls", async () => {
     try {
       await program.methods
-        .updateConfig(true, null, null, null, null, null, null, null, null, null)
+        .setPaused(PAUSE_CLAIMS, true, 1)
         .accounts({
           faucetConfig: faucetConfigPDA,
           authority: user1.publicKey,
@@ -493,6 +558,10 @@ describe("afrodevsols", () => {
           faucetConfig: faucetConfigPDA,
           treasuryVault: treasuryVaultPDA,
           claimerRecord: recordPDA,
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
           claimer: user1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -506,7 +575,1561 @@ describe("afrodevsols", () => {
   });
 
   // ──────────────────────────────────────────────────────────
-  // TEST 14: FINAL STATE CHECK
+  // TEST 14: ESCROWED GRANT — recipient must accept
+  // ──────────────────────────────────────────────────────────
+  it("✅ escrowed grant is paid only when the recipient accepts", async () => {
+    const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    const nonce = config.grantNonce;
+
+    const grantRecordPDA = getPDA(
+      [
+        Buffer.from("grant_record"),
+        authority.publicKey.toBuffer(),
+        user1.publicKey.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .escrowGrant(user1.publicKey, sol(0.5), "bounty-escrow", true, hours(24))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        grantRecord: grantRecordPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    let grant = await program.account.grantRecord.fetch(grantRecordPDA);
+    assert.equal(grant.status, 1, "Grant should be pending");
+
+    // Someone else cannot accept it
+    try {
+      await program.methods
+        .acceptGrant()
+        .accounts({ grantRecord: grantRecordPDA, recipient: user2.publicKey })
+        .signers([user2])
+        .rpc();
+      assert.fail("Should have thrown Unauthorized");
+    } catch (e: any) {
+      assert.include(e.message, "Unauthorized");
+    }
+
+    const balanceBefore = await provider.connection.getBalance(user1.publicKey);
+
+    await program.methods
+      .acceptGrant()
+      .accounts({ grantRecord: grantRecordPDA, recipient: user1.publicKey })
+      .signers([user1])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(user1.publicKey);
+    grant = await program.account.grantRecord.fetch(grantRecordPDA);
+
+    assert.isAbove(balanceAfter, balanceBefore + 0.49 * LAMPORTS_PER_SOL);
+    assert.equal(grant.status, 2, "Grant should be accepted");
+    assert.isAbove(grant.settledAt.toNumber(), 0);
+    console.log("    Escrowed grant accepted ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 15: MILESTONE GRANT — tranches released one at a time
+  // ──────────────────────────────────────────────────────────
+  it("✅ milestone grant releases tranches and cancels the remainder", async () => {
+    const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    const nonce = config.grantNonce;
+
+    const grantRecordPDA = getPDA(
+      [
+        Buffer.from("grant_record"),
+        authority.publicKey.toBuffer(),
+        user2.publicKey.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const grantMilestonesPDA = getPDA(
+      [Buffer.from("grant_milestones"), grantRecordPDA.toBuffer()],
+      program.programId
+    );
+
+    const hashes = [1, 2, 3].map((n) => Array(32).fill(n));
+
+    await program.methods
+      .createMilestoneGrant(
+        user2.publicKey,
+        "builder-grant",
+        true,
+        [sol(0.2), sol(0.3), sol(0.5)],
+        hashes
+      )
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        grantRecord: grantRecordPDA,
+        grantMilestones: grantMilestonesPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const balanceBefore = await provider.connection.getBalance(user2.publicKey);
+
+    await program.methods
+      .approveMilestone(0)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        grantRecord: grantRecordPDA,
+        grantMilestones: grantMilestonesPDA,
+        recipientWallet: user2.publicKey,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(user2.publicKey);
+    assert.equal(balanceAfter - balanceBefore, 0.2 * LAMPORTS_PER_SOL);
+
+    const treasuryBefore = await provider.connection.getBalance(treasuryVaultPDA);
+
+    await program.methods
+      .cancelMilestoneGrant()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        grantRecord: grantRecordPDA,
+        grantMilestones: grantMilestonesPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const treasuryAfter = await provider.connection.getBalance(treasuryVaultPDA);
+    const grant = await program.account.grantRecord.fetch(grantRecordPDA);
+    const milestones = await program.account.grantMilestones.fetch(grantMilestonesPDA);
+
+    assert.equal(treasuryAfter - treasuryBefore, 0.8 * LAMPORTS_PER_SOL);
+    assert.equal(grant.status, 6, "Grant should be cancelled");
+    assert.equal(milestones.releasedTotal.toString(), sol(0.2).toString());
+
+    // Settled, so the admin can take the rent of both accounts back
+    await program.methods
+      .closeGrantRecord()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        grantRecord: grantRecordPDA,
+        grantMilestones: grantMilestonesPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(grantRecordPDA));
+    assert.isNull(await provider.connection.getAccountInfo(grantMilestonesPDA));
+    const after = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(after.grantNonce.toString(), nonce.addn(1).toString());
+    console.log("    Milestone release + cancel + close works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 16: REFERRAL CODES — claim resolves a short code
+  // ──────────────────────────────────────────────────────────
+  it("✅ resolves a registered referral code on claim", async () => {
+    const code = "user3-" + Date.now().toString(36);
+    const referralCodePDA = getPDA(
+      [Buffer.from("referral_code"), Buffer.from(code)],
+      program.programId
+    );
+
+    await program.methods
+      .registerReferralCode(code)
+      .accounts({
+        referralCode: referralCodePDA,
+        owner: user3.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([user3])
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const referralPDA = getPDA(
+      [Buffer.from("referral"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const user3RecordPDA = getPDA(
+      [Buffer.from("claimer"), user3.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .claimWithCode(sol(0.1), code)
+      .accounts({
+        claim: {
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: recordPDA,
+          referralRecord: referralPDA,
+          referrerRecord: user3RecordPDA,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        },
+        referralCode: referralCodePDA,
+      })
+      .signers([newUser])
+      .rpc();
+
+    const record = await program.account.claimerRecord.fetch(recordPDA);
+    const codeAccount = await program.account.referralCode.fetch(referralCodePDA);
+    assert.equal(record.referredBy?.toString(), user3.publicKey.toString());
+    assert.equal(codeAccount.timesUsed.toString(), "1");
+
+    await program.methods
+      .releaseReferralCode(code)
+      .accounts({ referralCode: referralCodePDA, owner: user3.publicKey })
+      .signers([user3])
+      .rpc();
+
+    const released = await provider.connection.getAccountInfo(referralCodePDA);
+    assert.isNull(released, "Code account should be closed");
+    console.log("    Referral code registered, used and released ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 17: REFERRAL UNLOCK — referrer waits for Nth claim
+  // ──────────────────────────────────────────────────────────
+  it("✅ holds the referrer bonus until the referred wallet's Nth claim", async () => {
+    await program.methods
+      .updateReferralConfig(new anchor.BN(2), null, null, null, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const referralPDA = getPDA(
+      [Buffer.from("referral"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const user1RecordPDA = getPDA(
+      [Buffer.from("claimer"), user1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const claimAccounts = {
+      faucetConfig: faucetConfigPDA,
+      treasuryVault: treasuryVaultPDA,
+      claimerRecord: recordPDA,
+      referralRecord: referralPDA,
+      referrerRecord: user1RecordPDA,
+      claimersLeaderboard: null,
+      referrersLeaderboard: null,
+      claimer: newUser.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    const before = await program.account.claimerRecord.fetch(user1RecordPDA);
+
+    await program.methods
+      .claim(sol(0.1), user1.publicKey)
+      .accounts(claimAccounts)
+      .signers([newUser])
+      .rpc();
+
+    let referral = await program.account.referralRecord.fetch(referralPDA);
+    let referrer = await program.account.claimerRecord.fetch(user1RecordPDA);
+    assert.equal(referral.confirmedAt.toNumber(), 0, "Referral should still be pending");
+    assert.equal(referrer.pendingReferralBonus.toString(), before.pendingReferralBonus.toString());
+
+    await new Promise((r) => setTimeout(r, 500));
+
+    // Dropping the referral accounts can't dodge the unlock
+    try {
+      await program.methods
+        .claim(sol(0.25), null)
+        .accounts({ ...claimAccounts, referralRecord: null, referrerRecord: null })
+        .signers([newUser])
+        .rpc();
+      assert.fail("Should have thrown ReferralRecordRequired");
+    } catch (e: any) {
+      assert.include(e.message, "ReferralRecordRequired");
+    }
+
+    await program.methods
+      .claim(sol(0.25), null)
+      .accounts(claimAccounts)
+      .signers([newUser])
+      .rpc();
+
+    referral = await program.account.referralRecord.fetch(referralPDA);
+    referrer = await program.account.claimerRecord.fetch(user1RecordPDA);
+    assert.isAbove(referral.confirmedAt.toNumber(), 0, "Referral should be confirmed");
+    assert.equal(
+      referrer.pendingReferralBonus.sub(before.pendingReferralBonus).toString(),
+      REFERRAL_BONUS_REFERRER.toString()
+    );
+
+    await program.methods
+      .updateReferralConfig(new anchor.BN(1), null, null, null, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Referral unlocked on second claim ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 18: REFERRER VALIDATION — unknown referrers rejected
+  // ──────────────────────────────────────────────────────────
+  it("✅ rejects a referrer that has never used the faucet", async () => {
+    const stranger = Keypair.generate();
+    const strangerRecordPDA = getPDA(
+      [Buffer.from("claimer"), stranger.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const referralPDA = getPDA(
+      [Buffer.from("referral"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .claim(sol(0.1), stranger.publicKey)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: recordPDA,
+          referralRecord: referralPDA,
+          referrerRecord: null,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newUser])
+        .rpc();
+      assert.fail("Should have thrown InvalidReferral");
+    } catch (e: any) {
+      assert.include(e.message, "InvalidReferral");
+    }
+
+    const strangerRecord = await provider.connection.getAccountInfo(strangerRecordPDA);
+    assert.isNull(strangerRecord);
+    console.log("    Unknown referrer rejected ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 19: MULTI-LEVEL REFERRALS — ancestor gets a share
+  // ──────────────────────────────────────────────────────────
+  it("✅ credits the referrer's own referrer a decayed share", async () => {
+    // user3 referred user2 earlier; now user2 refers a new wallet
+    await program.methods
+      .updateReferralConfig(null, null, null, 1, 5000, 5000)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const referralPDA = getPDA(
+      [Buffer.from("referral"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const user2RecordPDA = getPDA(
+      [Buffer.from("claimer"), user2.publicKey.toBuffer()],
+      program.programId
+    );
+    const user3RecordPDA = getPDA(
+      [Buffer.from("claimer"), user3.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const ancestorBefore = await program.account.claimerRecord.fetch(user3RecordPDA);
+
+    // Leaving the ancestor out can't skip its share
+    try {
+      await program.methods
+        .claim(sol(0.1), user2.publicKey)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: recordPDA,
+          referralRecord: referralPDA,
+          referrerRecord: user2RecordPDA,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newUser])
+        .rpc();
+      assert.fail("Should have thrown InvalidReferral");
+    } catch (e: any) {
+      assert.include(e.message, "InvalidReferral");
+    }
+
+    await program.methods
+      .claim(sol(0.1), user2.publicKey)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA,
+        referralRecord: referralPDA,
+        referrerRecord: user2RecordPDA,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
+        claimer: newUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: user3RecordPDA, isWritable: true, isSigner: false },
+      ])
+      .signers([newUser])
+      .rpc();
+
+    const ancestorAfter = await program.account.claimerRecord.fetch(user3RecordPDA);
+    assert.equal(
+      ancestorAfter.pendingReferralBonus.sub(ancestorBefore.pendingReferralBonus).toString(),
+      REFERRAL_BONUS_REFERRER.divn(2).toString()
+    );
+
+    await program.methods
+      .updateReferralConfig(null, null, null, 0, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Second-level referrer credited ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 20: REFERRAL LIMITS — daily cap per referrer
+  // ──────────────────────────────────────────────────────────
+  it("✅ enforces the per-referrer daily referral cap", async () => {
+    const user1RecordPDA = getPDA(
+      [Buffer.from("claimer"), user1.publicKey.toBuffer()],
+      program.programId
+    );
+    const referrer = await program.account.claimerRecord.fetch(user1RecordPDA);
+
+    // Cap at whatever user1 has already referred today
+    await program.methods
+      .updateReferralLimits(referrer.referralsToday, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+
+    try {
+      await program.methods
+        .claim(sol(0.1), user1.publicKey)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+            program.programId
+          ),
+          referralRecord: getPDA(
+            [Buffer.from("referral"), newUser.publicKey.toBuffer()],
+            program.programId
+          ),
+          referrerRecord: user1RecordPDA,
+          claimersLeaderboard: null,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newUser])
+        .rpc();
+      assert.fail("Should have thrown ReferralDailyCapReached");
+    } catch (e: any) {
+      assert.include(e.message, "ReferralDailyCapReached");
+    }
+
+    await program.methods
+      .updateReferralLimits(new anchor.BN(0), null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.isAbove(config.outstandingReferralBonuses.toNumber(), 0);
+    console.log("    Daily referral cap enforced ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 21: LIABILITY RESERVE — owed bonuses can't be withdrawn
+  // ──────────────────────────────────────────────────────────
+  it("✅ withdrawals cannot spend SOL owed to referrers", async () => {
+    const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    const balance = await provider.connection.getBalance(treasuryVaultPDA);
+    const RENT_RESERVE = 10_000_000;
+
+    const liabilities = config.outstandingReferralBonuses.toNumber();
+    assert.isAbove(liabilities, 0);
+
+    // One lamport more than what sits above reserve + liabilities
+    const tooMuch = new anchor.BN(balance - RENT_RESERVE - liabilities + 1);
+
+    try {
+      await program.methods
+        .withdrawTreasury(tooMuch)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown LiabilityReserveViolation");
+    } catch (e: any) {
+      assert.include(e.message, "LiabilityReserveViolation");
+      console.log("    Referral liabilities protected ✓");
+    }
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 22: STREAKS — first claim starts a streak
+  // ──────────────────────────────────────────────────────────
+  it("✅ tracks claim streaks on the claimer record", async () => {
+    await program.methods
+      .updateStreakConfig(500, 2500)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const balanceBefore = await provider.connection.getBalance(newUser.publicKey);
+
+    await program.methods
+      .claim(sol(0.1), null)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: null,
+        referrersLeaderboard: null,
+        claimer: newUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newUser])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(newUser.publicKey);
+    const record = await program.account.claimerRecord.fetch(recordPDA);
+
+    // Day one of a streak earns no bonus
+    assert.equal(record.currentStreak.toString(), "1");
+    assert.equal(record.longestStreak.toString(), "1");
+    assert.isBelow(balanceAfter - balanceBefore, 0.11 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .updateStreakConfig(0, 0)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Streak started ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 23: LEADERBOARDS — claims and donations are ranked
+  // ──────────────────────────────────────────────────────────
+  it("✅ keeps claimer and donor leaderboards sorted", async () => {
+    for (const [kind, board] of [[0, claimersBoardPDA], [2, donorsBoardPDA]] as const) {
+      await program.methods
+        .initializeLeaderboard(kind)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          leaderboard: board,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    // Once a board exists it can't be left out
+    try {
+      await program.methods
+        .fundTreasury(sol(0.01))
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          funderRecord: getPDA(
+            [Buffer.from("funder"), authority.publicKey.toBuffer()],
+            program.programId
+          ),
+          donorsLeaderboard: null,
+          funder: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown LeaderboardRequired");
+    } catch (e: any) {
+      assert.include(e.message, "LeaderboardRequired");
+    }
+
+    await program.methods
+      .fundTreasury(sol(1))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        funderRecord: getPDA(
+          [Buffer.from("funder"), authority.publicKey.toBuffer()],
+          program.programId
+        ),
+        donorsLeaderboard: donorsBoardPDA,
+        funder: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+
+    await program.methods
+      .claim(sol(0.5), null)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: getPDA(
+          [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+          program.programId
+        ),
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: claimersBoardPDA,
+        referrersLeaderboard: null,
+        claimer: newUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newUser])
+      .rpc();
+
+    const claimers = await program.account.leaderboard.fetch(claimersBoardPDA);
+    const donors = await program.account.leaderboard.fetch(donorsBoardPDA);
+
+    assert.equal(claimers.entries[0].wallet.toString(), newUser.publicKey.toString());
+    assert.equal(claimers.entries[0].score.toString(), sol(0.5).toString());
+    const authorityFunder = await program.account.funderRecord.fetch(
+      getPDA([Buffer.from("funder"), authority.publicKey.toBuffer()], program.programId)
+    );
+    assert.equal(donors.entries[0].wallet.toString(), authority.publicKey.toString());
+    assert.equal(donors.entries[0].score.toString(), authorityFunder.totalDonated.toString());
+    console.log("    Leaderboards updated ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 24: FUNDER RECORDS — donations are recorded on-chain
+  // ──────────────────────────────────────────────────────────
+  it("✅ records funder totals and display name", async () => {
+    const funderRecordPDA = getPDA(
+      [Buffer.from("funder"), user1.publicKey.toBuffer()],
+      program.programId
+    );
+    const configBefore = await program.account.faucetConfig.fetch(faucetConfigPDA);
+
+    for (let i = 0; i < 2; i++) {
+      await program.methods
+        .fundTreasury(sol(0.2))
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          funderRecord: funderRecordPDA,
+          donorsLeaderboard: donorsBoardPDA,
+          funder: user1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+    }
+
+    await program.methods
+      .setFunderDisplayName("Lagos Devs")
+      .accounts({ funderRecord: funderRecordPDA, funder: user1.publicKey })
+      .signers([user1])
+      .rpc();
+
+    const record = await program.account.funderRecord.fetch(funderRecordPDA);
+    const configAfter = await program.account.faucetConfig.fetch(faucetConfigPDA);
+
+    assert.equal(record.totalDonated.toString(), sol(0.4).toString());
+    assert.equal(record.donationCount.toString(), "2");
+    assert.equal(Buffer.from(record.displayName).toString().replace(/\0+$/, ""), "Lagos Devs");
+    assert.equal(
+      configAfter.totalFundsReceived.sub(configBefore.totalFundsReceived).toString(),
+      sol(0.4).toString()
+    );
+    console.log("    Funder recorded ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 25: LOW TREASURY — auto-pause and auto-resume
+  // ──────────────────────────────────────────────────────────
+  it("✅ auto-pauses below the low-water mark and resumes when refilled", async () => {
+    const balance = await provider.connection.getBalance(treasuryVaultPDA);
+
+    await program.methods
+      .updateTreasuryAlerts(
+        new anchor.BN(balance - 0.05 * LAMPORTS_PER_SOL),
+        new anchor.BN(balance + 0.5 * LAMPORTS_PER_SOL),
+        true
+      )
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    await program.methods
+      .withdrawTreasury(sol(0.1))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    let config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, PAUSE_CLAIMS | PAUSE_REFERRAL_PAYOUT);
+    assert.equal(config.pauseReasons[0], 2, "Claims paused for low treasury");
+
+    await program.methods
+      .fundTreasury(sol(1))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        funderRecord: getPDA(
+          [Buffer.from("funder"), authority.publicKey.toBuffer()],
+          program.programId
+        ),
+        donorsLeaderboard: donorsBoardPDA,
+        funder: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, 0);
+    assert.equal(config.pauseReasons[0], 0);
+
+    await program.methods
+      .updateTreasuryAlerts(new anchor.BN(0), new anchor.BN(0), false)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Low-treasury auto-pause works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 26: RUNWAY SCALING — payouts shrink as treasury drains
+  // ──────────────────────────────────────────────────────────
+  it("✅ scales claim amounts by treasury runway", async () => {
+    const balance = await provider.connection.getBalance(treasuryVaultPDA);
+    const big = new anchor.BN(balance).mul(new anchor.BN(10));
+
+    // A single step above the current balance halves every payout
+    await program.methods
+      .updateRunwayScaling(
+        2,
+        null,
+        null,
+        null,
+        [big, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0)],
+        [5000, 10000, 10000, 10000]
+      )
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const vaultBefore = await provider.connection.getBalance(treasuryVaultPDA);
+
+    await program.methods
+      .claim(sol(0.1), null)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: claimersBoardPDA,
+        referrersLeaderboard: null,
+        claimer: newUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newUser])
+      .rpc();
+
+    const vaultAfter = await provider.connection.getBalance(treasuryVaultPDA);
+    const record = await program.account.claimerRecord.fetch(recordPDA);
+
+    assert.equal(vaultBefore - vaultAfter, 0.05 * LAMPORTS_PER_SOL);
+    assert.equal(record.totalClaimed.toString(), sol(0.05).toString());
+
+    await program.methods
+      .updateRunwayScaling(0, null, null, null, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Runway scaling halves payouts ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 27: SPONSORED CLAIM — a wallet with 0 SOL can claim
+  // ──────────────────────────────────────────────────────────
+  it("✅ lets a relayer sponsor a claim for an empty wallet", async () => {
+    await program.methods
+      .updateSponsorConfig(true, authority.publicKey, sol(0.001), sol(0.01))
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    // Never airdropped: the claimer only signs
+    const newUser = Keypair.generate();
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const sponsorIx = await program.methods
+      .sponsorClaimerRecord()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        claimerRecord: recordPDA,
+        referralRecord: null,
+        claimer: newUser.publicKey,
+        relayer: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    await program.methods
+      .sponsoredClaim(sol(0.1), null)
+      .accounts({
+        claim: {
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: recordPDA,
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: claimersBoardPDA,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        },
+        relayer: authority.publicKey,
+      })
+      .preInstructions([sponsorIx])
+      .signers([newUser])
+      .rpc();
+
+    const balance = await provider.connection.getBalance(newUser.publicKey);
+    const record = await program.account.claimerRecord.fetch(recordPDA);
+    let config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+
+    assert.equal(balance, 0.1 * LAMPORTS_PER_SOL, "Claimer paid nothing");
+    assert.equal(record.totalClaims.toString(), "1");
+    assert.equal(config.sponsorReimbursedToday.toString(), sol(0.001).toString());
+
+    await program.methods
+      .updateSponsorConfig(false, null, null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.sponsoredClaimsEnabled, false);
+    console.log("    Sponsored claim works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 28: ELIGIBILITY VIEW — explains why a claim would fail
+  // ──────────────────────────────────────────────────────────
+  it("✅ reports claim eligibility without sending a claim", async () => {
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const recordPDA = getPDA(
+      [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+      program.programId
+    );
+
+    let result = await program.methods
+      .checkEligibility(newUser.publicKey, sol(0.1))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA, // Not created yet: a new wallet
+      })
+      .view();
+
+    assert.equal(result.eligible, true);
+    assert.equal(result.errorCode, 0);
+    assert.equal(result.tierIndex, 0);
+
+    await program.methods
+      .claim(sol(0.1), null)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA,
+        referralRecord: null,
+        referrerRecord: null,
+        claimersLeaderboard: claimersBoardPDA,
+        referrersLeaderboard: null,
+        claimer: newUser.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([newUser])
+      .rpc();
+
+    result = await program.methods
+      .checkEligibility(newUser.publicKey, sol(0.1))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        claimerRecord: recordPDA,
+      })
+      .view();
+
+    assert.equal(result.eligible, false);
+    assert.equal(result.errorCode, 6002, "CooldownActive");
+    assert.isAbove(result.cooldownRemaining[0].toNumber(), 0);
+    assert.equal(result.cooldownRemaining[1].toNumber(), 0);
+    assert.isAbove(result.nextEligibleAt.toNumber(), 0);
+    console.log("    Eligibility view works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 29: CPI CLAIM — only allowlisted programs may call it
+  // ──────────────────────────────────────────────────────────
+  it("❌ rejects cpi_claim sent directly instead of through an integrator", async () => {
+    const integrator = Keypair.generate().publicKey;
+    await program.methods
+      .setIntegrator(integrator, true)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    let config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.isTrue(config.integratorPrograms.some((p: PublicKey) => p.equals(integrator)));
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const cpiClaim = (claimersLeaderboard: PublicKey | null) =>
+      program.methods
+        .cpiClaim(sol(0.1))
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+            program.programId
+          ),
+          claimersLeaderboard,
+          claimer: newUser.publicKey,
+          destination: newUser.publicKey,
+          payer: newUser.publicKey,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newUser])
+        .rpc();
+
+    // The claimers board from TEST 23 can't be left out of a CPI claim either
+    try {
+      await cpiClaim(null);
+      assert.fail("Should have thrown LeaderboardRequired");
+    } catch (e: any) {
+      assert.include(e.message, "LeaderboardRequired");
+    }
+
+    try {
+      await cpiClaim(claimersBoardPDA);
+      assert.fail("Should have thrown UnauthorizedIntegrator");
+    } catch (e: any) {
+      assert.include(e.message, "UnauthorizedIntegrator");
+    }
+
+    await program.methods
+      .setIntegrator(integrator, false)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.isFalse(config.integratorPrograms.some((p: PublicKey) => p.equals(integrator)));
+    console.log("    Direct cpi_claim correctly rejected ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 30: GRANULAR PAUSE — only the paused features stop
+  // ──────────────────────────────────────────────────────────
+  it("✅ pauses a single tier and withdrawals without stopping other claims", async () => {
+    await program.methods
+      .setPaused(PAUSE_TIER_0 | PAUSE_WITHDRAWALS, true, 7)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    let config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, PAUSE_TIER_0 | PAUSE_WITHDRAWALS);
+    assert.equal(config.pauseReasons[1], 7, "Tier 0 reason");
+    assert.equal(config.pauseReasons[8], 7, "Withdrawal reason");
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const claimAccounts = {
+      faucetConfig: faucetConfigPDA,
+      treasuryVault: treasuryVaultPDA,
+      claimerRecord: getPDA(
+        [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+        program.programId
+      ),
+      referralRecord: null,
+      referrerRecord: null,
+      claimersLeaderboard: claimersBoardPDA,
+      referrersLeaderboard: null,
+      claimer: newUser.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    try {
+      await program.methods
+        .claim(sol(0.1), null)
+        .accounts(claimAccounts)
+        .signers([newUser])
+        .rpc();
+      assert.fail("Should have thrown TierPaused");
+    } catch (e: any) {
+      assert.include(e.message, "TierPaused");
+    }
+
+    // Tier 1 is unaffected
+    await program.methods
+      .claim(sol(0.25), null)
+      .accounts(claimAccounts)
+      .signers([newUser])
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawTreasury(sol(0.01))
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown WithdrawalsPaused");
+    } catch (e: any) {
+      assert.include(e.message, "WithdrawalsPaused");
+    }
+
+    await program.methods
+      .setPaused(PAUSE_TIER_0 | PAUSE_WITHDRAWALS, false, 0)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, 0);
+    assert.equal(config.pauseReasons[1], 0);
+    console.log("    Granular pause works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 31: CIRCUIT BREAKER — trips on a burst of claims
+  // ──────────────────────────────────────────────────────────
+  it("✅ trips the circuit breaker on fast outflow until the admin resets it", async () => {
+    // Start from an empty window so earlier tests don't count
+    await program.methods
+      .resetCircuitBreaker()
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    await program.methods
+      .updateCircuitBreaker(sol(0.15), 15)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const claimAs = async (user: Keypair) =>
+      program.methods
+        .claim(sol(0.1), null)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), user.publicKey.toBuffer()],
+            program.programId
+          ),
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: claimersBoardPDA,
+          referrersLeaderboard: null,
+          claimer: user.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+    const users = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const user of users) {
+      await airdrop(provider.connection, user.publicKey);
+    }
+
+    await claimAs(users[0]);
+    let config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, 0, "Under the limit");
+
+    // Takes the window to 0.2 SOL: goes through, then trips
+    await claimAs(users[1]);
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, PAUSE_CLAIMS);
+    assert.equal(config.pauseReasons[0], 3, "Paused by the circuit breaker");
+
+    try {
+      await claimAs(users[2]);
+      assert.fail("Should have thrown FaucetPaused");
+    } catch (e: any) {
+      assert.include(e.message, "FaucetPaused");
+    }
+
+    await program.methods
+      .resetCircuitBreaker()
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    await program.methods
+      .updateCircuitBreaker(new anchor.BN(0), null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.pausedFeatures, 0);
+    await claimAs(users[2]);
+    console.log("    Circuit breaker works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 32: CAMPAIGN — own budget, rules and sweep-back
+  // ──────────────────────────────────────────────────────────
+  it("✅ runs a campaign from its own budget and sweeps the rest back", async () => {
+    const campaignId = new anchor.BN(1);
+    const campaignPDA = getPDA(
+      [Buffer.from("campaign"), campaignId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createCampaign(
+        campaignId,
+        "Lagos Hackathon",
+        sol(0.3),
+        new anchor.BN(now - 60),
+        new anchor.BN(now + 3600),
+        [sol(0.1), new anchor.BN(0), new anchor.BN(0), new anchor.BN(0)],
+        hours(1),
+        sol(0.1),
+        Array(32).fill(0)
+      )
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        campaign: campaignPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const hacker = Keypair.generate();
+    await airdrop(provider.connection, hacker.publicKey);
+    const claimCampaign = () =>
+      program.methods
+        .claimCampaign(sol(0.1), [])
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          campaign: campaignPDA,
+          campaignClaimRecord: getPDA(
+            [Buffer.from("campaign_claim"), campaignPDA.toBuffer(), hacker.publicKey.toBuffer()],
+            program.programId
+          ),
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), hacker.publicKey.toBuffer()],
+            program.programId
+          ),
+          claimer: hacker.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([hacker])
+        .rpc();
+
+    await claimCampaign();
+    try {
+      await claimCampaign();
+      assert.fail("Should have thrown CooldownActive");
+    } catch (e: any) {
+      assert.include(e.message, "CooldownActive");
+    }
+
+    const sweep = () =>
+      program.methods
+        .sweepCampaign()
+        .accounts({
+          campaign: campaignPDA,
+          treasuryVault: treasuryVaultPDA,
+          cranker: hacker.publicKey,
+        })
+        .signers([hacker])
+        .rpc();
+
+    try {
+      await sweep();
+      assert.fail("Should have thrown CampaignNotFinished");
+    } catch (e: any) {
+      assert.include(e.message, "CampaignNotFinished");
+    }
+
+    await program.methods
+      .closeCampaign()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        campaign: campaignPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+    const treasuryBefore = await provider.connection.getBalance(treasuryVaultPDA);
+    await sweep();
+    const treasuryAfter = await provider.connection.getBalance(treasuryVaultPDA);
+    assert.equal(treasuryAfter - treasuryBefore, 0.2 * LAMPORTS_PER_SOL);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    assert.equal(campaign.totalClaimed.toString(), sol(0.1).toString());
+    assert.equal(campaign.claimCount.toNumber(), 1);
+    assert.equal(campaign.uniqueClaimers.toNumber(), 1);
+    assert.equal(campaign.sweptAmount.toString(), sol(0.2).toString());
+    assert.equal(campaign.status, 2, "Swept");
+
+    await program.methods
+      .closeCampaignAccount()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        campaign: campaignPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(campaignPDA));
+    console.log("    Campaign lifecycle works ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 33: VOUCHERS — one redemption per wallet
+  // ──────────────────────────────────────────────────────────
+  it("✅ redeems a multi-use voucher once per wallet", async () => {
+    // The code seeds an ed25519 keypair; only its public key goes on chain
+    const codeKeypair = (secret: string) =>
+      Keypair.fromSeed(createHash("sha256").update(secret).digest());
+    const code = "lagos-meetup-" + Keypair.generate().publicKey.toBase58();
+    const codeKey = codeKeypair(code).publicKey;
+    const voucherPDA = getPDA([Buffer.from("voucher"), codeKey.toBuffer()], program.programId);
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createVoucher(codeKey, sol(0.05), new anchor.BN(now + 3600), 2)
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        voucher: voucherPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // The code's key signs voucher ‖ redeemer, so a seen
+    // redemption can't be replayed for another wallet
+    const redeem = (user: Keypair, secret: string, signedFor: Keypair = user) =>
+      program.methods
+        .redeemVoucher()
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: codeKeypair(secret).secretKey,
+            message: Buffer.concat([voucherPDA.toBuffer(), signedFor.publicKey.toBuffer()]),
+          }),
+        ])
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          voucher: voucherPDA,
+          voucherRedemption: getPDA(
+            [Buffer.from("voucher_redemption"), voucherPDA.toBuffer(), user.publicKey.toBuffer()],
+            program.programId
+          ),
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), user.publicKey.toBuffer()],
+            program.programId
+          ),
+          redeemer: user.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+    const expectError = async (promise: Promise<string>, name: string) => {
+      try {
+        await promise;
+        assert.fail(`Should have thrown ${name}`);
+      } catch (e: any) {
+        assert.include(e.message, name);
+      }
+    };
+
+    const users = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const user of users) {
+      await airdrop(provider.connection, user.publicKey);
+    }
+
+    await expectError(redeem(users[0], "wrong-code"), "InvalidVoucherCode");
+    await expectError(redeem(users[2], code, users[0]), "InvalidVoucherCode");
+    await redeem(users[0], code);
+    await expectError(redeem(users[0], code), "VoucherAlreadyRedeemed");
+    await redeem(users[1], code);
+    await expectError(redeem(users[2], code), "VoucherUsedUp");
+
+    const voucher = await program.account.voucher.fetch(voucherPDA);
+    assert.equal(voucher.uses, 2);
+    assert.equal(voucher.totalRedeemed.toString(), sol(0.1).toString());
+
+    await program.methods
+      .closeVoucher()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        voucher: voucherPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(voucherPDA));
+    console.log("    Vouchers work ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 34: BOUNTIES — submit and award
+  // ──────────────────────────────────────────────────────────
+  it("✅ escrows a bounty and pays the awarded submitter", async () => {
+    const bountyId = (await program.account.faucetConfig.fetch(faucetConfigPDA)).bountyNonce;
+    const bountyPDA = getPDA(
+      [Buffer.from("bounty"), bountyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const descriptionHash = createHash("sha256").update("Write an Anchor tutorial").digest();
+
+    await program.methods
+      .createBounty(sol(0.2), new anchor.BN(now + 3600), Array.from(descriptionHash))
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        treasuryVault: treasuryVaultPDA,
+        bounty: bountyPDA,
+        authority: authority.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const submissionPDA = (user: Keypair) =>
+      getPDA(
+        [Buffer.from("bounty_submission"), bountyPDA.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+    const hunters = [Keypair.generate(), Keypair.generate()];
+    for (const hunter of hunters) {
+      await airdrop(provider.connection, hunter.publicKey);
+      const workHash = createHash("sha256")
+        .update("https://example.com/" + hunter.publicKey.toBase58())
+        .digest();
+      await program.methods
+        .submitBounty(Array.from(workHash))
+        .accounts({
+          bounty: bountyPDA,
+          submission: submissionPDA(hunter),
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), hunter.publicKey.toBuffer()],
+            program.programId
+          ),
+          submitter: hunter.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([hunter])
+        .rpc();
+    }
+
+    // Not past the deadline and review period yet
+    try {
+      await program.methods
+        .expireBounty()
+        .accounts({
+          bounty: bountyPDA,
+          treasuryVault: treasuryVaultPDA,
+          cranker: authority.publicKey,
+        })
+        .rpc();
+      assert.fail("Should have thrown BountyNotExpired");
+    } catch (e: any) {
+      assert.include(e.message, "BountyNotExpired");
+    }
+
+    const winner = hunters[1];
+    const before = await provider.connection.getBalance(winner.publicKey);
+    await program.methods
+      .awardBounty()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        bounty: bountyPDA,
+        submission: submissionPDA(winner),
+        winner: winner.publicKey,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    const after = await provider.connection.getBalance(winner.publicKey);
+    assert.equal(after - before, sol(0.2).toNumber());
+
+    const bounty = await program.account.bounty.fetch(bountyPDA);
+    assert.equal(bounty.submissionCount, 2);
+    assert.ok(bounty.winner.equals(winner.publicKey));
+    assert.equal(bounty.bountyId.toString(), bountyId.toString());
+
+    await program.methods
+      .closeBounty()
+      .accounts({
+        faucetConfig: faucetConfigPDA,
+        bounty: bountyPDA,
+        authority: authority.publicKey,
+      })
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(bountyPDA));
+    console.log("    Bounties work ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 35: TIER REQUIREMENTS — higher tiers unlock with history
+  // ──────────────────────────────────────────────────────────
+  it("✅ locks a tier until the wallet has enough prior claims", async () => {
+    await program.methods
+      .updateTierRequirements(1, new anchor.BN(1), null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+
+    const newUser = Keypair.generate();
+    await airdrop(provider.connection, newUser.publicKey);
+    const claim = (amount: anchor.BN) =>
+      program.methods
+        .claim(amount, null)
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          treasuryVault: treasuryVaultPDA,
+          claimerRecord: getPDA(
+            [Buffer.from("claimer"), newUser.publicKey.toBuffer()],
+            program.programId
+          ),
+          referralRecord: null,
+          referrerRecord: null,
+          claimersLeaderboard: claimersBoardPDA,
+          referrersLeaderboard: null,
+          claimer: newUser.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([newUser])
+        .rpc();
+
+    try {
+      await claim(sol(0.25));
+      assert.fail("Should have thrown TierLocked");
+    } catch (e: any) {
+      assert.include(e.message, "TierLocked");
+      assert.isTrue(
+        e.logs.some((line: string) => line.includes("Tier 1 locked: needs 1 more claims"))
+      );
+    }
+
+    // One tier-0 claim unlocks tier 1
+    await claim(sol(0.1));
+    await new Promise((r) => setTimeout(r, 500));
+    await claim(sol(0.25));
+
+    await program.methods
+      .updateTierRequirements(1, new anchor.BN(0), null, null)
+      .accounts({ faucetConfig: faucetConfigPDA, authority: authority.publicKey })
+      .rpc();
+    console.log("    Tier requirements work ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 36: MIGRATIONS — current layouts are left alone
+  // ──────────────────────────────────────────────────────────
+  it("✅ refuses to migrate accounts already in the current layout", async () => {
+    const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
+    assert.equal(config.layoutVersion, 1);
+
+    try {
+      await program.methods
+        .migrateConfig()
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          authority: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown AccountAlreadyMigrated");
+    } catch (e: any) {
+      assert.include(e.message, "AccountAlreadyMigrated");
+    }
+
+    try {
+      await program.methods
+        .migrateRecord()
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          record: getPDA([Buffer.from("claimer"), user1.publicKey.toBuffer()], program.programId),
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown AccountAlreadyMigrated");
+    } catch (e: any) {
+      assert.include(e.message, "AccountAlreadyMigrated");
+    }
+
+    // Anything that isn't a migratable record is rejected
+    try {
+      await program.methods
+        .migrateRecord()
+        .accounts({
+          faucetConfig: faucetConfigPDA,
+          record: claimersBoardPDA,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      assert.fail("Should have thrown InvalidAccountLayout");
+    } catch (e: any) {
+      assert.include(e.message, "InvalidAccountLayout");
+    }
+    console.log("    Migrations are idempotent ✓");
+  });
+
+  // ──────────────────────────────────────────────────────────
+  // TEST 37: FINAL STATE CHECK
   // ──────────────────────────────────────────────────────────
   it("✅ final state is consistent", async () => {
     const config = await program.account.faucetConfig.fetch(faucetConfigPDA);
@@ -514,13 +2137,13 @@ describe("afrodevsols", () => {
     assert.isAbove(config.totalClaims.toNumber(), 0);
     assert.isAbove(config.totalUniqueClaimers.toNumber(), 0);
     assert.isAbove(config.totalSolDistributed.toNumber(), 0);
-    assert.equal(config.isPaused, false);
+    assert.equal(config.pausedFeatures, 0);
 
     console.log("\n    ── Final State ──────────────────────────");
     console.log("    Total claims:    ", config.totalClaims.toString());
     console.log("    Unique claimers: ", config.totalUniqueClaimers.toString());
     console.log("    SOL distributed: ", config.totalSolDistributed.toNumber() / LAMPORTS_PER_SOL, "SOL");
-    console.log("    Is paused:       ", config.isPaused);
+    console.log("    Paused features: ", config.pausedFeatures);
     console.log("    ─────────────────────────────────────────");
   });
 });
\ No newline at end of file